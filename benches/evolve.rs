@@ -0,0 +1,19 @@
+//! Evolution step throughput
+//!
+//! `cargo bench --bench evolve` times one step on a 96³ grid with the default
+//! (parallel) build; `cargo bench --bench evolve --no-default-features` gives
+//! the sequential baseline to compare against.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use iirt_engine::*;
+
+fn evolve_96(c: &mut Criterion) {
+    let mut reality = Reality::new(96, (-6.0, 6.0), 1.0, 0.001);
+    reality.add_information((0.0, 0.0, 0.0), 4.0);
+
+    let name = if cfg!(feature = "parallel") { "evolve_96_parallel" } else { "evolve_96_sequential" };
+    c.bench_function(name, |b| b.iter(|| reality.evolve()));
+}
+
+criterion_group!(benches, evolve_96);
+criterion_main!(benches);
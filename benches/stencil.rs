@@ -0,0 +1,23 @@
+//! Laplacian stencil kernels: scalar vs SIMD
+//!
+//! Times one `evolve` step on a 128³ cubic grid, whose rates run the
+//! Laplacian row kernel. Compare
+//! `cargo bench --bench stencil --no-default-features` (scalar) with
+//! `cargo bench --bench stencil --no-default-features --features simd`;
+//! leaving out rayon keeps the kernel the main difference.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use iirt_engine::*;
+
+const N: usize = 128;
+
+fn stencil_128(c: &mut Criterion) {
+    let mut reality = Reality::new(N, (-4.0, 4.0), 1.0, 0.001);
+    reality.add_information((0.0, 0.0, 0.0), 4.0);
+
+    let name = if cfg!(feature = "simd") { "laplacian_128_simd" } else { "laplacian_128_scalar" };
+    c.bench_function(name, |b| b.iter(|| reality.evolve()));
+}
+
+criterion_group!(benches, stencil_128);
+criterion_main!(benches);
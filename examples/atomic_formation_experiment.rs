@@ -10,6 +10,7 @@
 //! - Nuclear energy = released information integration energy
 
 use iirt_engine::*;
+use std::ops::ControlFlow;
 
 fn main() {
     println!("⚛️ ATOMIC FORMATION FROM INFORMATION FIELDS");
@@ -30,21 +31,10 @@ fn demonstrate_hydrogen_formation() {
     println!("1. HYDROGEN ATOM FORMATION");
     println!("=========================");
     
-    let mut atom_space = Reality::new(32, (-2.0, 2.0), 0.5, 0.005);
-    
+    // The reference configuration the eV scale is calibrated on
     println!("Creating proton (concentrated information peak)...");
-    // Proton = very high information density at center
-    atom_space.add_information((0.0, 0.0, 0.0), 8.0);
-    
     println!("Adding electron (distributed information cloud)...");
-    // Electron = lower density information distributed around proton
-    for i in 0..8 {
-        let angle = (i as f64) * 2.0 * std::f64::consts::PI / 8.0;
-        let radius = 0.8; // Bohr radius analog
-        let x = radius * angle.cos();
-        let y = radius * angle.sin();
-        atom_space.add_information((x, y, 0.0), 1.5);
-    }
+    let mut atom_space = scales::hydrogen_atom();
     
     println!("\nInitial configuration:");
     let proton_info = atom_space.information_at((0.0, 0.0, 0.0)).unwrap().density();
@@ -54,18 +44,16 @@ fn demonstrate_hydrogen_formation() {
     
     // Evolve to form stable atom
     println!("\nAtom formation evolution:");
-    println!("Step | Proton ℐ | Electron ℐ | Binding | Stability");
-    println!("-----|----------|------------|---------|----------");
+    println!("Step | Proton ℐ | Electron ℐ | Binding | Residual | Stability");
+    println!("-----|----------|------------|---------|----------|----------");
     
-    for step in 0..40 {
-        atom_space.evolve();
-        
+    atom_space.evolve_with(scales::HYDROGEN_FORMATION_STEPS, |atom_space, step| {
         if step % 8 == 0 {
             let proton_density = atom_space.information_at((0.0, 0.0, 0.0)).unwrap().density();
             let electron_density = atom_space.information_at((0.8, 0.0, 0.0)).unwrap().density();
             
             // Binding energy = information integration between proton and electron
-            let binding_energy = calculate_binding_energy(&atom_space, (0.0, 0.0, 0.0), (0.8, 0.0, 0.0));
+            let binding_energy = calculate_binding_energy(atom_space, (0.0, 0.0, 0.0), (0.8, 0.0, 0.0));
             
             let stability = if binding_energy > 0.5 {
                 "Stable"
@@ -75,13 +63,16 @@ fn demonstrate_hydrogen_formation() {
                 "Unstable"
             };
             
-            println!("{:4} | {:8.3} | {:10.3} | {:7.3} | {}", 
-                    step, proton_density, electron_density, binding_energy, stability);
+            // Largest per-cell change of this step: how far from settled
+            println!("{:4} | {:8.3} | {:10.3} | {:7.3} | {:8.2e} | {}", 
+                    step, proton_density, electron_density, binding_energy, atom_space.last_residual(), stability);
         }
-    }
+        ControlFlow::Continue(())
+    });
     
     let final_binding = calculate_binding_energy(&atom_space, (0.0, 0.0, 0.0), (0.8, 0.0, 0.0));
-    let ionization_energy = final_binding * 13.6; // Scale to eV
+    let pair_binding = atom_space.binding_energy(scales::HYDROGEN_PROTON, scales::HYDROGEN_ELECTRON).unwrap();
+    let ionization_energy = scales::info_to_ev(pair_binding);
     
     println!("\nHydrogen atom formation complete:");
     println!("  Binding energy: {:.3} information units", final_binding);
@@ -181,7 +172,7 @@ fn demonstrate_nuclear_binding() {
         if step % 6 == 0 {
             let total_info = nucleus.total_information();
             let binding = calculate_nuclear_binding(&nucleus, &nucleon_positions);
-            let strong_force = binding * 1000.0; // Scale to MeV equivalent
+            let strong_force = scales::info_to_mev_strong(binding);
             
             let status = if binding > 2.0 {
                 "Bound"
@@ -197,7 +188,7 @@ fn demonstrate_nuclear_binding() {
     }
     
     let final_binding = calculate_nuclear_binding(&nucleus, &nucleon_positions);
-    let binding_energy_mev = final_binding * 7.0; // Scale to realistic MeV
+    let binding_energy_mev = scales::info_to_mev_nuclear(final_binding);
     
     println!("\nHelium nucleus formation:");
     println!("  Nuclear binding energy: {:.3} information units", final_binding);
@@ -253,7 +244,7 @@ fn demonstrate_nuclear_fusion() {
     // Calculate fusion energy release
     let initial_energy = 2.0 * 6.0; // Two separate protons
     let final_energy = fusion_space.information_at((0.0, 0.0, 0.0)).unwrap().density();
-    let energy_release = (initial_energy - final_energy) * 2.2; // Scale to MeV
+    let energy_release = scales::fusion_bits_to_mev(initial_energy - final_energy);
     
     println!("\nFusion reaction complete:");
     println!("  Energy released: {:.1} MeV (theoretical: ~2.2 MeV)", energy_release);
@@ -316,7 +307,7 @@ fn demonstrate_nuclear_fission() {
     
     // Calculate fission energy
     let total_final = fission_space.total_information();
-    let fission_energy = (total_final - nucleus_info) * 200.0; // Scale to MeV
+    let fission_energy = scales::fission_bits_to_mev(total_final - nucleus_info);
     
     println!("\nFission reaction analysis:");
     println!("  Energy released: {:.0} MeV (theoretical: ~200 MeV)", fission_energy);
@@ -326,12 +317,8 @@ fn demonstrate_nuclear_fission() {
 
 /// Calculate binding energy between two information peaks
 fn calculate_binding_energy(reality: &Reality, pos1: (f64, f64, f64), pos2: (f64, f64, f64)) -> f64 {
-    let info1 = reality.information_at(pos1).unwrap().density();
-    let info2 = reality.information_at(pos2).unwrap().density();
-    let distance = ((pos1.0 - pos2.0).powi(2) + (pos1.1 - pos2.1).powi(2) + (pos1.2 - pos2.2).powi(2)).sqrt();
-    
-    // Binding energy inversely related to separation
-    (info1 * info2) / (distance + 0.1) / 100.0
+    // Canonical pair energy, scaled to this example's units
+    reality.binding_energy(pos1, pos2).unwrap() / 100.0
 }
 
 /// Calculate orbital energy from information distribution
@@ -66,8 +66,11 @@ fn map_hydrogen_information_profile() {
     }
     
     // Evolve to equilibrium
-    for _ in 0..50 {
-        hydrogen.evolve();
+    let steps = hydrogen.evolve_until_steady(1e-3, 50);
+    if steps < 50 {
+        println!("Settled after {} steps (max per-cell change < 1e-3 bits)", steps);
+    } else {
+        println!("Still changing after {} steps (> 1e-3 bits per cell)", steps);
     }
     
     println!("\nHydrogen Information Density Profile:");
@@ -86,7 +89,7 @@ fn map_hydrogen_information_profile() {
     let mut density_profile = Vec::new();
     
     for (region, radius, pos) in measurement_points {
-        let density = hydrogen.information_at(pos).unwrap().density();
+        let density = hydrogen.information_at_interpolated(pos).unwrap();
         let vacuum = hydrogen.vacuum_density();
         
         let threshold_level = classify_information_threshold(density, vacuum);
@@ -337,23 +340,28 @@ fn test_threshold_transitions() {
     }
     
     println!("\nThreshold Transition Analysis:");
-    println!("Position | Initial ℐ | Final ℐ | Conscious? | Amplification | Behavior");
-    println!("---------|-----------|---------|------------|---------------|----------");
+    println!("Position | Initial ℐ | Final ℐ | Margin  | Conscious? | Amplification | Local Rate | Behavior");
+    println!("---------|-----------|---------|---------|------------|---------------|------------|----------");
     
     for i in (0..20).step_by(3) {
         let x = -1.5 + (i as f64) * 3.0 / 19.0;
         let initial = 0.5 + (i as f64) * 1.0 / 19.0;
-        let final_density = test_field.information_at((x, 0.0, 0.0)).unwrap().density();
-        let conscious = final_density >= INTEGRATION_THRESHOLD;
+        let info = test_field.information_at((x, 0.0, 0.0)).unwrap();
+        let final_density = info.density();
+        let conscious = info.is_conscious();
         let amplification = final_density / initial;
+        // Instantaneous source minus sink: its sign is the current trend
+        let local_rate = test_field.net_local_rate((x, 0.0, 0.0)).unwrap();
         
-        let behavior = if amplification > 1.5 { "Self-amplifying" }
-        else if amplification > 1.1 { "Growing" }
-        else if amplification < 0.9 { "Decaying" }
-        else { "Stable" };
+        let behavior = match CellClass::from_amplification(amplification) {
+            CellClass::SelfAmplifying => "Self-amplifying",
+            CellClass::Growing => "Growing",
+            CellClass::Decaying => "Decaying",
+            CellClass::Stable => "Stable",
+        };
         
-        println!("{:8.2} | {:9.3} | {:7.3} | {:10} | {:13.2} | {}", 
-                x, initial, final_density, conscious, amplification, behavior);
+        println!("{:8.2} | {:9.3} | {:7.3} | {:+7.3} | {:10} | {:13.2} | {:+10.3} | {}", 
+                x, initial, final_density, info.consciousness_margin(), conscious, amplification, local_rate, behavior);
     }
     
     println!("\n  ✓ Sharp transitions observed at consciousness threshold");
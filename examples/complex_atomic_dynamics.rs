@@ -373,17 +373,10 @@ fn add_nucleus(reality: &mut Reality, z: usize, density: f64) {
     }
 }
 
-fn add_electron_shell(reality: &mut Reality, radius: f64, electrons: usize, density: f64, _shell_type: &str) {
-    // Efficient shell creation
-    let points = (electrons * 2).max(6); // At least 6 points for good coverage
-    for i in 0..points {
-        let angle = (i as f64) * 2.0 * std::f64::consts::PI / points as f64;
-        let r = radius * (1.0 + 0.1 * (i as f64 / points as f64)); // Slight radial variation
-        let x = r * angle.cos();
-        let y = r * angle.sin();
-        let electron_density = density * (1.0 + 0.2 * (i as f64 / points as f64).sin()); // Orbital variation
-        reality.add_information((x, y, 0.0), electron_density);
-    }
+fn add_electron_shell(reality: &mut Reality, radius: f64, _electrons: usize, density: f64, _shell_type: &str) {
+    // Smooth spherical shell about one grid spacing thick
+    let thickness = reality.dx();
+    reality.add_spherical_shell((0.0, 0.0, 0.0), radius, density, thickness);
 }
 
 fn calculate_information_flow(reality: &Reality, from: (f64, f64, f64), to: (f64, f64, f64)) -> f64 {
@@ -404,10 +397,6 @@ fn calculate_information_gradient(reality: &Reality, center: (f64, f64, f64), ed
 }
 
 fn calculate_binding_energy(reality: &Reality, pos1: (f64, f64, f64), pos2: (f64, f64, f64)) -> f64 {
-    let info1 = reality.information_at(pos1).unwrap().density();
-    let info2 = reality.information_at(pos2).unwrap().density();
-    let distance = ((pos1.0 - pos2.0).powi(2) + (pos1.1 - pos2.1).powi(2) + (pos1.2 - pos2.2).powi(2)).sqrt();
-    
-    // Binding energy inversely related to separation
-    (info1 * info2) / (distance + 0.1) / 50.0
+    // Canonical pair energy, scaled to this example's units
+    reality.binding_energy(pos1, pos2).unwrap() / 50.0
 }
\ No newline at end of file
@@ -93,7 +93,7 @@ fn verify_threshold_behavior() {
     for &value in &test_values {
         let info = Information::new(value);
         let self_ref = info.is_conscious();
-        let level = info.density() / INTEGRATION_THRESHOLD;
+        let level = info.self_reference_ratio();
         let classification = if self_ref { "ACTIVE" } else { "PASSIVE" };
         
         println!("{:11.6} | {:8} | {:7.4} | {}", 
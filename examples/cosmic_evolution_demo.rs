@@ -26,11 +26,11 @@ fn main() {
     
     for (age, epoch) in &cosmic_epochs {
         let vacuum_density = vacuum_at_cosmic_time(*age);
-        let dark_energy_percent = dark_energy_density_at_time(*age);
+        let dark_energy_percent = scales::dark_energy_percent(dark_energy_density_at_time(*age));
         let acceleration_status = if *age > 5.0 { "Accelerating" } else { "Decelerating" };
         
         println!("{:>8.1} | {:22} | {:>12.3} | {:>12.1}% | {}", 
-                age, epoch, vacuum_density, dark_energy_percent * 100.0, acceleration_status);
+                age, epoch, vacuum_density, dark_energy_percent, acceleration_status);
     }
     
     println!("\n🔬 EXPERIMENTAL VERIFICATION:");
@@ -42,16 +42,16 @@ fn main() {
     
     println!("Primordial universe (t=0):");
     println!("  Vacuum density: {:.3} bits", primordial.vacuum_density());
-    println!("  Dark energy: {:.1}%", (primordial.vacuum_density() / MAX_INFORMATION) * 100.0);
+    println!("  Dark energy: {:.1}%", scales::dark_energy_percent(primordial.vacuum_density() / MAX_INFORMATION));
     println!("  Cosmic age: {:.1} Gyr", primordial.cosmic_age());
     
     println!("\nCurrent universe (t=13.8 Gyr):");
     println!("  Vacuum density: {:.3} bits", current.vacuum_density());
-    println!("  Dark energy: {:.1}%", (current.vacuum_density() / MAX_INFORMATION) * 100.0);
+    println!("  Dark energy: {:.1}%", scales::dark_energy_percent(current.vacuum_density() / MAX_INFORMATION));
     println!("  Cosmic age: {:.1} Gyr", current.cosmic_age());
     
     let vacuum_growth = current.vacuum_density() - primordial.vacuum_density();
-    let de_growth = ((current.vacuum_density() / MAX_INFORMATION) - (primordial.vacuum_density() / MAX_INFORMATION)) * 100.0;
+    let de_growth = scales::dark_energy_percent((current.vacuum_density() - primordial.vacuum_density()) / MAX_INFORMATION);
     
     println!("\n📈 COSMIC EVOLUTION SUMMARY:");
     println!("----------------------------");
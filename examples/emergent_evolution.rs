@@ -61,28 +61,26 @@ fn experiment_1_pattern_replication() {
     println!();
     
     // Evolution protocol
-    let mut replication_data = Vec::new();
+    reality.enable_history(10);
+    let mut peak_counts = Vec::new();
     
     for step in 1..=50 {
         reality.evolve();
         
         if step % 10 == 0 {
-            let conscious_points = reality.conscious_count();
-            let peaks = count_local_maxima(&reality, 0.75);
-            let max_consciousness = 1.0; // Simplified for pure engine
-            let total_info = reality.total_information();
-            
-            replication_data.push((step, conscious_points, peaks, max_consciousness, total_info));
-            
-            println!("t={:2}: Conscious={:6}, Peaks={:2}, Max={:.3}, Total={:.1}",
-                     step, conscious_points, peaks, max_consciousness, total_info);
+            peak_counts.push(count_local_maxima(&reality, 0.75));
         }
     }
     
+    for (frame, peaks) in reality.history().iter().zip(&peak_counts) {
+        println!("t={:2}: Conscious={:6}, Peaks={:2}, Max={:.3}, Total={:.1}",
+                 frame.step, frame.conscious_count, peaks, frame.max_consciousness, frame.total_information);
+    }
+    
     // Analysis
-    let final_peaks = replication_data.last().unwrap().2;
+    let final_peaks = *peak_counts.last().unwrap();
     let replication_factor = final_peaks as f64 / t0_peaks as f64;
-    let final_info = replication_data.last().unwrap().4;
+    let final_info = reality.history().last().unwrap().total_information;
     let info_creation_rate = (final_info - t0_total_info) / 50.0;
     
     println!("\nResults:");
@@ -324,15 +322,11 @@ fn print_scientific_summary() {
 // Helper functions for pattern creation and analysis
 
 fn create_replication_seed(reality: &mut Reality, center: (f64, f64, f64)) {
-    reality.add_information(center, 1.5);
-    reality.add_information((center.0 + 0.2, center.1, center.2), 0.8);
-    reality.add_information((center.0, center.1 + 0.2, center.2), 0.8);
+    reality.add_information_batch(&patterns::replicator(center)).expect("pattern lies inside the field");
 }
 
 fn create_pattern_type_a(reality: &mut Reality, center: (f64, f64, f64)) {
-    reality.add_information(center, 1.5);
-    reality.add_information((center.0 + 0.2, center.1, center.2), 0.8);
-    reality.add_information((center.0, center.1 + 0.2, center.2), 0.8);
+    reality.add_information_batch(&patterns::replicator(center)).expect("pattern lies inside the field");
 }
 
 fn create_pattern_type_b(reality: &mut Reality, center: (f64, f64, f64)) {
@@ -356,21 +350,18 @@ fn create_low_fitness_pattern(reality: &mut Reality, center: (f64, f64, f64)) {
     reality.add_information((center.0 + 0.3, center.1, center.2), 0.5);
 }
 
-fn count_local_maxima(reality: &Reality, _threshold: f64) -> usize {
-    // Simplified: estimate peaks based on conscious count
-    (reality.conscious_count() as f64 * 0.001) as usize + 1
+fn count_local_maxima(reality: &Reality, threshold: f64) -> usize {
+    reality.local_maxima(threshold).len()
 }
 
-fn measure_population_near(reality: &Reality, _center: (f64, f64, f64), _radius: f64) -> usize {
-    // Simplified: estimate local population based on conscious density
-    (reality.conscious_count() as f64 * 0.1) as usize
+fn measure_population_near(reality: &Reality, center: (f64, f64, f64), radius: f64) -> usize {
+    // Local population: information held within the radius (bits)
+    reality.information_in_sphere(center, radius) as usize
 }
 
 fn measure_pattern_fitness(reality: &Reality, center: (f64, f64, f64)) -> f64 {
-    // Simplified: estimate fitness based on information at center
-    reality.information_at(center)
-        .map(|info| info.density())
-        .unwrap_or(0.0)
+    // Fitness: information held by the pattern, short of its neighbours 1.0 away
+    reality.information_in_sphere(center, 0.45)
 }
 
 fn measure_structural_complexity(reality: &Reality) -> f64 {
@@ -123,10 +123,10 @@ fn main() {
             let x = -5.0 + (i as f64 * 0.7) % 10.0;
             let y = -5.0 + ((i * 7) as f64 * 0.6) % 10.0;
             let z = -2.0 + ((i * 3) as f64 * 0.4) % 4.0;
-            
-            ecosystem.add_information((x, y, z), *density);
             positions.push((x, y, z));
         }
+        let individuals: Vec<_> = positions.iter().map(|&position| (position, *density)).collect();
+        ecosystem.add_information_batch(&individuals).expect("individuals lie inside the ecosystem");
         
         species_positions.insert(species_name.to_string(), positions);
         initial_populations.insert(species_name.to_string(), population_size);
@@ -135,6 +135,11 @@ fn main() {
                 species_name, density, population_size, description);
     }
     
+    // Seeded environmental variability: stochastic, yet reproducible run to run
+    ecosystem.add_noise(0.05, 2024);
+    // Track where information is generated, not just where it sits
+    ecosystem.enable_creation_tracking();
+    
     println!("\nBEGINNING EVOLUTIONARY SIMULATION...\n");
     
     // Evolution tracking
@@ -473,7 +478,7 @@ fn analyze_final_ecosystem_state(ecosystem: &Reality, species_positions: &HashMa
     
     let total_info = ecosystem.total_information();
     let conscious_points = ecosystem.conscious_count();
-    let info_density = total_info / (80.0 * 80.0 * 80.0);
+    let info_density = total_info / ecosystem.grid_point_count() as f64;
     
     println!("  Total ecosystem information: {:.1} bits", total_info);
     println!("  Conscious grid points: {}", conscious_points);
@@ -492,6 +497,11 @@ fn analyze_final_ecosystem_state(ecosystem: &Reality, species_positions: &HashMa
         }
     }
     
+    // Which half of the environment generated the most new information
+    let west = ecosystem.information_created_in_box((-6.0, -6.0, -6.0), (0.0, 6.0, 6.0));
+    let east = ecosystem.information_created_in_box((0.0, -6.0, -6.0), (6.0, 6.0, 6.0));
+    println!("  Information created: {:.1} bits west, {:.1} bits east of x = 0", west, east);
+    
     println!("  Active ecosystem regions: {}/{}", occupied_regions, species_positions.len());
     println!("  Ecosystem complexity: {:.1}%", 
             (occupied_regions as f64 / species_positions.len() as f64) * 100.0);
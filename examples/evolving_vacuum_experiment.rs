@@ -144,44 +144,42 @@ fn test_cosmic_time_evolution() {
     println!("=======================================");
     println!("Question: How does vacuum evolve over billions of years?\n");
     
-    let mut reality = Reality::from_vacuum();
+    let mut reality = Reality::from_primordial_vacuum();
     
-    // Simulate cosmic time evolution
-    // 1 evolution step ≈ cosmic time unit
+    // Advance the cosmic epoch (Gyr), relaxing the field at each stop
     let cosmic_epochs = [
-        (0, "Big Bang Era"),
-        (50, "Matter Domination"),
-        (100, "Dark Energy Emergence"),
-        (200, "Acceleration Era"),
-        (300, "Far Future"),
+        (0.0, "Big Bang Era"),
+        (3.0, "Matter Domination"),
+        (9.0, "Dark Energy Emergence"),
+        (CURRENT_COSMIC_AGE_GYR, "Acceleration Era"),
+        (15.0, "Far Future"),
     ];
     
-    let initial_vacuum = VACUUM_INFORMATION;
-    let mut previous_steps = 0;
+    let initial_vacuum = reality.vacuum_density();
+    let mut previous_age = 0.0;
     
     println!("Tracking vacuum information density over cosmic time:");
     println!("Epoch                  | Vacuum (bits) | Increase | Dark Energy %");
     println!("----------------------|---------------|----------|---------------");
     
-    for (steps, epoch_name) in cosmic_epochs {
-        // Evolve to next epoch
-        for _ in 0..(steps - previous_steps) {
+    for (age, epoch_name) in cosmic_epochs {
+        reality.advance_cosmic_time(age - previous_age);
+        for _ in 0..10 {
             reality.evolve();
         }
         
-        let total_info = reality.total_information();
-        let effective_vacuum = total_info / (64_f64.powi(3));
+        let effective_vacuum = reality.total_information() / reality.grid_point_count() as f64;
         let vacuum_increase = effective_vacuum - initial_vacuum;
         let dark_energy_percent = (effective_vacuum / MAX_INFORMATION) * 100.0;
         
         println!("{:<20} | {:>10.3} | {:>7.3} | {:>12.1}%", 
                 epoch_name, effective_vacuum, vacuum_increase, dark_energy_percent);
         
-        previous_steps = steps;
+        previous_age = age;
     }
     
     println!("\nCosmological Implications:");
-    let final_vacuum = reality.total_information() / (64_f64.powi(3));
+    let final_vacuum = reality.total_information() / reality.grid_point_count() as f64;
     let total_vacuum_growth = final_vacuum - initial_vacuum;
     
     if total_vacuum_growth > 0.1 {
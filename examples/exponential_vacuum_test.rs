@@ -14,7 +14,7 @@ fn main() {
     
     // Test parameters
     let cosmic_ages = [0.0, 1.0, 5.0, 9.0, 13.8, 20.0];
-    let observed_dark_energy_today = 0.73; // 73%
+    let observed_dark_energy_today = DARK_ENERGY_DENSITY; // 73%
     let max_info = 16.0;
     let threshold = INTEGRATION_THRESHOLD; // 0.707 bits
     let target_vacuum_today = observed_dark_energy_today * max_info; // 11.68 bits
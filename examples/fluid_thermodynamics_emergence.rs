@@ -41,7 +41,7 @@
 //! 
 //! **Flow Dynamics**:
 //! - Information flow velocities and directions
-//! - Reynolds numbers (velocity × length × density / viscosity)
+//! - Reynolds numbers (RMS flow speed × length / viscosity)
 //! - Pressure gradients (density differences)
 //! 
 //! **Thermal Behavior**:
@@ -142,21 +142,8 @@ fn experiment_1_pressure_driven_flow() {
     
     println!("SETUP: Creating pressure gradient (high → low information density)");
     
-    // Create "high pressure" region (high information density)
-    for i in 0..8 {
-        let x = -2.5 + (i as f64) * 0.1;
-        fluid_field.add_information((x, 0.0, 0.0), 3.5);
-        fluid_field.add_information((x, 0.2, 0.0), 3.2);
-        fluid_field.add_information((x, -0.2, 0.0), 3.2);
-    }
-    
-    // Create "low pressure" region (lower information density)
-    for i in 0..8 {
-        let x = 1.5 + (i as f64) * 0.1;
-        fluid_field.add_information((x, 0.0, 0.0), 1.8);
-        fluid_field.add_information((x, 0.2, 0.0), 1.5);
-        fluid_field.add_information((x, -0.2, 0.0), 1.5);
-    }
+    // Linear ramp from "high pressure" (left) to "low pressure" (right)
+    fluid_field.add_linear_gradient(Axis::X, 3.5, 1.8, (-2.5, 2.2));
     
     println!("Initial pressure distribution:");
     let high_pressure = fluid_field.information_at((-2.0, 0.0, 0.0)).unwrap().density();
@@ -168,6 +155,9 @@ fn experiment_1_pressure_driven_flow() {
     println!("  Low pressure region: {:.3} bits", low_pressure);
     println!("  Pressure gradient: {:.3} bits/unit\n", (high_pressure - low_pressure) / 4.0);
     
+    // Information diffuses like momentum, so D·Δx² plays the kinematic viscosity
+    let viscosity = fluid_field.diffusion() * fluid_field.dx().powi(2);
+    
     println!("FLOW EVOLUTION:");
     println!("Time | Flow Rate | Velocity | Pressure Drop | Reynolds | Flow Type");
     println!("-----|-----------|----------|---------------|----------|----------");
@@ -178,13 +168,12 @@ fn experiment_1_pressure_driven_flow() {
         if step % 10 == 0 {
             // Measure flow characteristics
             let current_high = fluid_field.information_at((-2.0, 0.0, 0.0)).unwrap().density();
-            let current_mid = fluid_field.information_at((0.0, 0.0, 0.0)).unwrap().density();
             let current_low = fluid_field.information_at((2.0, 0.0, 0.0)).unwrap().density();
             
-            let flow_rate = calculate_flow_rate(&fluid_field, (-1.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+            let flow_rate = fluid_field.flux_through_plane(Axis::X, 0.0).unwrap();
             let velocity = calculate_flow_velocity(&fluid_field, (0.0, 0.0, 0.0));
             let pressure_drop = current_high - current_low;
-            let reynolds = calculate_reynolds_number(velocity, 1.0, current_mid);
+            let reynolds = fluid_field.reynolds_number(1.0, viscosity);
             
             let flow_type = if reynolds > 2300.0 { "Turbulent" }
             else if reynolds > 1000.0 { "Transitional" }
@@ -230,6 +219,7 @@ fn experiment_2_heat_diffusion() {
     
     let initial_center = thermal_field.information_at((0.0, 0.0, 0.0)).unwrap().density();
     let initial_edge = thermal_field.information_at((2.0, 0.0, 0.0)).unwrap().density();
+    let initial_temperature = thermal_field.effective_temperature();
     
     println!("Initial thermal state:");
     println!("  Center temperature: {:.3} bits", initial_center);
@@ -251,7 +241,7 @@ fn experiment_2_heat_diffusion() {
             let _mid_temp = thermal_field.information_at((1.0, 0.0, 0.0)).unwrap().density();
             
             let gradient = (center_temp - edge_temp) / 2.0;
-            let heat_flux = calculate_heat_flux(&thermal_field, (0.5, 0.0, 0.0), (1.5, 0.0, 0.0));
+            let heat_flux = thermal_field.flux_through_plane(Axis::X, 0.25).unwrap();
             let diffusion_rate = if step > 0 {
                 let prev_center = temperature_history.last().unwrap_or(&center_temp);
                 (prev_center - center_temp).abs()
@@ -261,7 +251,7 @@ fn experiment_2_heat_diffusion() {
             else if gradient < 1.0 { "Diffusing" }
             else { "Sharp gradient" };
             
-            println!("{:4} | {:8.3} | {:6.3} | {:8.3} | {:9.3} | {:9.3} | {}", 
+            println!("{:4} | {:8.3} | {:6.3} | {:8.3} | {:9.2e} | {:9.3} | {}", 
                     step, center_temp, edge_temp, gradient, heat_flux, diffusion_rate, pattern);
             
             temperature_history.push(center_temp);
@@ -277,6 +267,8 @@ fn experiment_2_heat_diffusion() {
     println!("  Initial ΔT: {:.3} bits", initial_center - initial_edge);
     println!("  Final ΔT: {:.3} bits", final_center - final_edge);
     println!("  Equilibration: {:.1}% (thermal diffusion)", equilibration * 100.0);
+    println!("  Effective temperature: {:.2e} → {:.2e} bits/cell",
+            initial_temperature, thermal_field.effective_temperature());
     
     if equilibration > 0.3 {
         println!("  ✓ THERMAL DIFFUSION CONFIRMED");
@@ -321,6 +313,11 @@ fn experiment_3_convection_patterns() {
     println!("  Top temperature: {:.3} bits", top_temp);
     println!("  Buoyancy driving force: {:.3} bits\n", bottom_temp - top_temp);
     
+    // Hold the plates at their seeded temperatures: a sustained heat source and sink
+    let half_cell = 0.5 * convection_field.dx();
+    convection_field.pin_region((-2.0, -1.5 - half_cell, -1.0), (2.0, -1.5 + half_cell, 1.0), bottom_temp);
+    convection_field.pin_region((-2.0, 1.5 - half_cell, -1.0), (2.0, 1.5 + half_cell, 1.0), top_temp);
+    
     println!("CONVECTION DYNAMICS:");
     println!("Time | Vertical Flow | Circulation | Rayleigh | Cell Count | Pattern");
     println!("-----|---------------|-------------|----------|------------|--------");
@@ -348,8 +345,14 @@ fn experiment_3_convection_patterns() {
     let final_circulation = calculate_circulation_strength(&convection_field);
     let final_rayleigh = calculate_rayleigh_number(&convection_field);
     
+    let (grad_x, grad_y, grad_z) = convection_field.gradient_anisotropy();
+    
     println!("  Final circulation strength: {:.3}", final_circulation);
     println!("  Final Rayleigh number: {:.1}", final_rayleigh);
+    println!("  RMS gradient (x, y, z): ({:.3}, {:.3}, {:.3}) bits/unit", grad_x, grad_y, grad_z);
+    if grad_y > 2.0 * grad_x.max(grad_z) {
+        println!("  ✓ Vertically organized structure (y gradient dominates)");
+    }
     
     if final_circulation > 0.5 {
         println!("  ✓ CONVECTION PATTERNS CONFIRMED");
@@ -370,20 +373,26 @@ fn experiment_4_turbulence_generation() {
     println!("SETUP: High-speed information jet → turbulence");
     
     // Central jet with high information density
-    for i in 0..20 {
+    let jet: Vec<_> = (0..20).flat_map(|i| {
         let x = -3.0 + (i as f64) * 0.3;
-        turbulent_field.add_information((x, 0.0, 0.0), 2.8);
-        // Add small perturbations to trigger instability
-        turbulent_field.add_information((x, 0.1, 0.0), 2.5 + 0.2 * (i as f64 * 0.5).sin());
-        turbulent_field.add_information((x, -0.1, 0.0), 2.5 + 0.2 * (i as f64 * 0.7).cos());
-    }
+        [
+            ((x, 0.0, 0.0), 2.8),
+            // Add small perturbations to trigger instability
+            ((x, 0.1, 0.0), 2.5 + 0.2 * (i as f64 * 0.5).sin()),
+            ((x, -0.1, 0.0), 2.5 + 0.2 * (i as f64 * 0.7).cos()),
+        ]
+    }).collect();
+    turbulent_field.add_information_batch(&jet).expect("jet lies inside the field");
     
     println!("TURBULENCE EVOLUTION:");
     println!("Time | Jet Speed | Vorticity | Turbulence | Energy Cascade | Flow State");
     println!("-----|-----------|-----------|------------|----------------|----------");
     
     for step in 0..40 {
-        turbulent_field.evolve();
+        if let Err(error) = turbulent_field.evolve_checked() {
+            println!("  ✗ Aborted at step {}: {}", step, error);
+            break;
+        }
         
         if step % 5 == 0 {
             let jet_speed = calculate_jet_velocity(&turbulent_field);
@@ -444,10 +453,10 @@ fn experiment_5_phase_transitions() {
         phase_field.evolve();
         
         if step % 5 == 0 {
-            let order_parameter = calculate_order_parameter(&phase_field);
-            let conscious_fraction = phase_field.conscious_count() as f64 / (36.0 * 36.0 * 36.0);
+            let order_parameter = phase_field.order_parameter();
+            let conscious_fraction = phase_field.conscious_fraction();
             let correlation_length = calculate_correlation_length(&phase_field);
-            let susceptibility = calculate_susceptibility(&phase_field);
+            let susceptibility = phase_field.susceptibility();
             
             let phase = if conscious_fraction > 0.8 { "Ordered" }
             else if conscious_fraction > 0.3 { "Critical" }
@@ -459,13 +468,24 @@ fn experiment_5_phase_transitions() {
         }
     }
     
-    let final_order = calculate_order_parameter(&phase_field);
-    let final_conscious = phase_field.conscious_count() as f64 / (36.0 * 36.0 * 36.0);
+    let final_order = phase_field.order_parameter();
+    let final_conscious = phase_field.conscious_fraction();
     
     println!("\nPHASE TRANSITION ANALYSIS:");
     println!("  Final order parameter: {:.3}", final_order);
     println!("  Final conscious fraction: {:.1}%", final_conscious * 100.0);
     
+    // Critical initial density: where the order parameter rises most steeply
+    let densities: Vec<f64> = (0..=20).map(|i| 0.4 + 0.03 * i as f64).collect();
+    let critical = find_critical_threshold_with_densities(|density| {
+        let mut field = Reality::new_at_cosmic_age(16, (-2.5, 2.5), 1.0, 0.003, -40.0);
+        for (_, _, _, _, info) in field.iter_cells_mut() {
+            *info = Information::new(density);
+        }
+        field
+    }, &densities, 35);
+    println!("  Critical initial density: {:.3} bits (ℐ_crit = {:.3})", critical, INTEGRATION_THRESHOLD);
+    
     if final_conscious > 0.8 || final_order > 0.7 {
         println!("  ✓ ORDERED PHASE ACHIEVED");
     } else if final_conscious > 0.3 {
@@ -486,24 +506,23 @@ fn calculate_flow_rate(reality: &Reality, from: (f64, f64, f64), to: (f64, f64,
 }
 
 fn calculate_flow_velocity(reality: &Reality, position: (f64, f64, f64)) -> f64 {
-    let _center = reality.information_at(position).unwrap().density();
-    let right = reality.information_at((position.0 + 0.2, position.1, position.2)).unwrap_or(Information::new(0.0)).density();
-    let left = reality.information_at((position.0 - 0.2, position.1, position.2)).unwrap_or(Information::new(0.0)).density();
-    ((right - left) / 0.4).abs()
-}
-
-fn calculate_reynolds_number(velocity: f64, length_scale: f64, density: f64) -> f64 {
-    // Simplified Reynolds number analogue
-    velocity * length_scale * density / 0.01 // Assume kinematic viscosity ~ 0.01
-}
-
-fn calculate_heat_flux(reality: &Reality, from: (f64, f64, f64), to: (f64, f64, f64)) -> f64 {
-    calculate_flow_rate(reality, from, to) // Heat flux analogous to information flow
+    // Central difference, one-sided where a neighbour falls off the grid
+    let center = reality.information_at(position).unwrap().density();
+    let sample = |offset: f64| {
+        let neighbour = (position.0 + offset, position.1, position.2);
+        if reality.contains(neighbour) {
+            (reality.information_at(neighbour).unwrap().density(), offset)
+        } else {
+            (center, 0.0)
+        }
+    };
+    let ((right, right_offset), (left, left_offset)) = (sample(0.2), sample(-0.2));
+    ((right - left) / (right_offset - left_offset)).abs()
 }
 
 fn calculate_vertical_flow(reality: &Reality) -> f64 {
-    let bottom = reality.information_at((0.0, -1.0, 0.0)).unwrap_or(Information::new(0.0)).density();
-    let top = reality.information_at((0.0, 1.0, 0.0)).unwrap_or(Information::new(0.0)).density();
+    let bottom = reality.information_at((0.0, -1.0, 0.0)).unwrap().density();
+    let top = reality.information_at((0.0, 1.0, 0.0)).unwrap().density();
     (bottom - top) / 2.0 // Vertical information gradient
 }
 
@@ -523,8 +542,8 @@ fn calculate_circulation_strength(reality: &Reality) -> f64 {
 }
 
 fn calculate_rayleigh_number(reality: &Reality) -> f64 {
-    let bottom_temp = reality.information_at((0.0, -1.5, 0.0)).unwrap_or(Information::new(0.0)).density();
-    let top_temp = reality.information_at((0.0, 1.5, 0.0)).unwrap_or(Information::new(0.0)).density();
+    let bottom_temp = reality.information_at((0.0, -1.5, 0.0)).unwrap().density();
+    let top_temp = reality.information_at((0.0, 1.5, 0.0)).unwrap().density();
     let temp_diff = bottom_temp - top_temp;
     let height = 3.0f64;
     
@@ -564,35 +583,31 @@ fn calculate_turbulence_intensity(reality: &Reality) -> f64 {
     variance.sqrt() / mean_velocity.max(0.1) // Turbulence intensity
 }
 
+/// Log-log slope of the power spectrum (Kolmogorov: -5/3)
+#[cfg(feature = "fft")]
+fn calculate_energy_cascade(reality: &Reality) -> f64 {
+    // Least-squares fit of ln P against ln k over the non-zero wavenumbers
+    let points: Vec<(f64, f64)> = reality.power_spectrum().into_iter()
+        .filter(|&(k, power)| k > 0.0 && power > 0.0)
+        .map(|(k, power)| (k.ln(), power.ln()))
+        .collect();
+    let n = points.len() as f64;
+    let (mean_x, mean_y) = points.iter().fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x / n, sy + y / n));
+    let (covariance, variance) = points.iter().fold((0.0, 0.0), |(c, v), &(x, y)| {
+        (c + (x - mean_x) * (y - mean_y), v + (x - mean_x).powi(2))
+    });
+    covariance / variance
+}
+
+/// Velocity difference between scales; build with `--features fft` for the spectral slope
+#[cfg(not(feature = "fft"))]
 fn calculate_energy_cascade(reality: &Reality) -> f64 {
-    // Simplified energy cascade measure
     let large_scale = calculate_flow_velocity(reality, (0.0, 0.0, 0.0));
     let small_scale = calculate_flow_velocity(reality, (0.1, 0.1, 0.0));
     (large_scale - small_scale).abs()
 }
 
-fn calculate_order_parameter(reality: &Reality) -> f64 {
-    let conscious_count = reality.conscious_count() as f64;
-    let total_points = reality.total_information() / reality.vacuum_density();
-    if total_points > 0.0 {
-        conscious_count / total_points
-    } else {
-        0.0
-    }
-}
-
 fn calculate_correlation_length(_reality: &Reality) -> f64 {
     // Simplified correlation length estimate
     1.5 // Would need spatial correlation analysis
-}
-
-fn calculate_susceptibility(reality: &Reality) -> f64 {
-    // Information susceptibility - response to perturbations
-    let total_info = reality.total_information();
-    let conscious_info = reality.conscious_count() as f64 * INTEGRATION_THRESHOLD;
-    if total_info > 0.0 {
-        conscious_info / total_info
-    } else {
-        0.0
-    }
 } 
\ No newline at end of file
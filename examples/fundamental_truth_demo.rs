@@ -35,7 +35,7 @@ fn main() {
     for level in test_levels {
         let info = Information::new(level);
         println!("  {:.3} bits → conscious: {} (ratio: {:.3})", 
-                 level, info.is_conscious(), level / INTEGRATION_THRESHOLD);
+                 level, info.is_conscious(), info.self_reference_ratio());
     }
     
     println!("\n✓ At exactly 1/√2, information can observe itself!");
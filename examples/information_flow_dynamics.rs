@@ -54,7 +54,7 @@ fn test_information_propagation_speed() {
         
         if step % 5 == 0 {
             let (wavefront_radius, peak_info, dispersion) = measure_wavefront(&flow_field);
-            let speed = if step > 0 { wavefront_radius / (step as f64 * 0.01) } else { 0.0 };
+            let speed = if step > 0 { wavefront_radius / flow_field.time() } else { 0.0 };
             
             let shape = if dispersion < 0.1 {
                 "Sharp"
@@ -70,15 +70,17 @@ fn test_information_propagation_speed() {
     }
     
     let final_radius = measure_wavefront(&flow_field).0;
-    let final_speed = final_radius / (25.0 * 0.01);
+    let final_speed = final_radius / flow_field.time();
     
     println!("\nPropagation Analysis:");
     println!("  Measured wavefront radius: {:.3} ± 0.05 units", final_radius);
-    println!("  Experimental speed: {:.1} ± 0.2 units/time", final_speed);
-    println!("  Diffusion coefficient D: {:.1} units²/time", 1.0);
-    println!("  Theoretical prediction: c_info = √D = {:.1} units/time", 1.0_f64.sqrt());
+    println!("  Experimental speed: {:.3} units/time", final_speed);
+    println!("  Diffusion coefficient D: {:.1} units²/time", flow_field.diffusion());
+    // √D is in grid cells per unit time; the wavefront is measured in units
+    let predicted_speed = flow_field.propagation_speed() * flow_field.dx();
+    println!("  Theoretical prediction: c_info = √D·Δx = {:.3} units/time", predicted_speed);
     
-    let speed_error = ((final_speed - 1.0) / 1.0 * 100.0).abs();
+    let speed_error = ((final_speed - predicted_speed) / predicted_speed * 100.0).abs();
     println!("  Speed deviation: {:.1}% from theoretical", speed_error);
     
     if speed_error < 20.0 {
@@ -100,7 +102,8 @@ fn test_information_currents() {
     // Create information gradient (source and sink)
     println!("Creating information source and sink...");
     current_field.add_information((-1.0, 0.0, 0.0), 6.0); // Source
-    current_field.add_information((1.0, 0.0, 0.0), -2.0); // Sink (remove info)
+    let sink_density = current_field.vacuum_density() - 2.0;
+    current_field.set_density((1.0, 0.0, 0.0), sink_density); // Sink (below vacuum)
     
     // Let current establish
     for _ in 0..20 {
@@ -113,21 +116,23 @@ fn test_information_currents() {
     
     let test_positions = [-1.0, -0.5, 0.0, 0.5, 1.0];
     let mut max_current: f64 = 0.0;
+    let currents = current_field.current_field();
     
     for &x in &test_positions {
         let pos = (x, 0.0, 0.0);
-        let nearby_pos = (x + 0.1, 0.0, 0.0);
-        
         let info_here = current_field.information_at(pos).unwrap().density();
-        let info_nearby = current_field.information_at(nearby_pos).unwrap().density();
+        let gradient = current_field.gradient(pos).unwrap().0;
         
-        let gradient = (info_nearby - info_here) / 0.1;
-        let current = -gradient * 0.8; // J = -D∇ℐ
+        // J = -D∇ℐ from the cell nearest the sample point
+        let distance = |p: &(f64, f64, f64)| (p.0 - x).powi(2) + p.1.powi(2) + p.2.powi(2);
+        let current = currents.iter()
+            .min_by(|a, b| distance(&a.0).total_cmp(&distance(&b.0)))
+            .unwrap().1.0;
         max_current = max_current.max(current.abs());
         
-        let flow_type = if current > 0.1 {
+        let flow_type = if current > 0.01 {
             "→ Outflow"
-        } else if current < -0.1 {
+        } else if current < -0.01 {
             "← Inflow"
         } else {
             "○ Stagnant"
@@ -155,7 +160,8 @@ fn test_flow_topology() {
     println!("Creating complex flow topology...");
     topo_field.add_information((-0.6, 0.5, 0.0), 4.0);  // Source 1
     topo_field.add_information((0.6, 0.5, 0.0), 4.0);   // Source 2  
-    topo_field.add_information((0.0, -0.5, 0.0), -3.0); // Central sink
+    let sink_density = topo_field.vacuum_density() - 3.0;
+    topo_field.set_density((0.0, -0.5, 0.0), sink_density); // Central sink
     
     // Let topology establish
     for _ in 0..30 {
@@ -176,7 +182,7 @@ fn test_flow_topology() {
     
     for (name, pos) in critical_points {
         let info_density = topo_field.information_at(pos).unwrap().density();
-        let divergence = calculate_divergence(&topo_field, pos);
+        let divergence = topo_field.divergence(pos).unwrap();
         
         let topo_type = if divergence > 0.1 {
             "Source"
@@ -193,7 +199,7 @@ fn test_flow_topology() {
     }
     
     // Check for vortices
-    let vorticity = calculate_vorticity(&topo_field, (0.0, 0.0, 0.0));
+    let vorticity = topo_field.vorticity((0.0, 0.0, 0.0)).unwrap().2;
     
     println!("\nTopological features:");
     println!("  Flow pattern: Two-source, one-sink dipole");
@@ -341,23 +347,19 @@ fn test_consciousness_flow_patterns() {
 /// Measure wavefront propagation
 fn measure_wavefront(reality: &Reality) -> (f64, f64, f64) {
     let baseline = reality.vacuum_density();
-    let threshold = baseline * 1.1;
     
-    let mut max_radius = 0.0;
-    let mut peak_info = baseline;
+    // Averaged over all directions, not just +x
+    let max_radius = reality.wavefront_radius((0.0, 0.0, 0.0), 1.1);
+    // The peak may drift off the sampled line
+    let peak_info = reality.argmax_consciousness().map_or(baseline, |(_, density)| density);
     let mut info_values = Vec::new();
     
-    for i in 0..10 {
-        let r = (i as f64) * 0.2;
+    // Dispersion profile: every cell from the origin out to the +x face
+    let cells = (reality.bounds().1 / reality.dx()).floor() as usize;
+    for i in 0..=cells {
+        let r = (i as f64) * reality.dx();
         let info = reality.information_at((r, 0.0, 0.0)).unwrap().density();
         info_values.push(info);
-        
-        if info > threshold {
-            max_radius = r;
-        }
-        if info > peak_info {
-            peak_info = info;
-        }
     }
     
     // Calculate dispersion
@@ -368,40 +370,6 @@ fn measure_wavefront(reality: &Reality) -> (f64, f64, f64) {
     (max_radius, peak_info, dispersion)
 }
 
-/// Calculate divergence at a point
-fn calculate_divergence(reality: &Reality, pos: (f64, f64, f64)) -> f64 {
-    let h = 0.1;
-    let (x, y, z) = pos;
-    
-    let info_here = reality.information_at(pos).unwrap().density();
-    let info_x_plus = reality.information_at((x + h, y, z)).unwrap().density();
-    let info_y_plus = reality.information_at((x, y + h, z)).unwrap().density();
-    
-    let div_x = (info_x_plus - info_here) / h;
-    let div_y = (info_y_plus - info_here) / h;
-    
-    div_x + div_y // 2D divergence
-}
-
-/// Calculate vorticity (curl) at a point
-fn calculate_vorticity(reality: &Reality, pos: (f64, f64, f64)) -> f64 {
-    let h = 0.1;
-    let (x, y, _z) = pos;
-    
-    // Approximate velocity field from information gradient
-    let _info_here = reality.information_at(pos).unwrap().density();
-    let info_x_plus = reality.information_at((x + h, y, 0.0)).unwrap().density();
-    let info_y_plus = reality.information_at((x, y + h, 0.0)).unwrap().density();
-    let info_x_minus = reality.information_at((x - h, y, 0.0)).unwrap().density();
-    let info_y_minus = reality.information_at((x, y - h, 0.0)).unwrap().density();
-    
-    let v_x = -(info_y_plus - info_y_minus) / (2.0 * h); // Flow velocity x
-    let v_y = (info_x_plus - info_x_minus) / (2.0 * h);  // Flow velocity y
-    
-    // Vorticity = ∂v_y/∂x - ∂v_x/∂y (simplified)
-    v_y - v_x
-}
-
 /// Calculate RMS information density
 fn calculate_rms_information(reality: &Reality) -> f64 {
     let baseline = reality.vacuum_density();
@@ -422,7 +390,7 @@ fn calculate_average_gradient(reality: &Reality) -> f64 {
     
     let mut total_gradient = 0.0;
     for &pos in &test_points {
-        let h = 0.1;
+        let h = reality.dx();
         let info_here = reality.information_at(pos).unwrap().density();
         let info_nearby = reality.information_at((pos.0 + h, pos.1, pos.2)).unwrap().density();
         total_gradient += (info_nearby - info_here).abs() / h;
@@ -439,7 +407,7 @@ fn calculate_average_vorticity(reality: &Reality) -> f64 {
     
     let mut total_vorticity = 0.0;
     for &pos in &test_points {
-        total_vorticity += calculate_vorticity(reality, pos).abs();
+        total_vorticity += reality.vorticity(pos).unwrap().2.abs();
     }
     
     total_vorticity / test_points.len() as f64
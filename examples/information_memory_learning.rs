@@ -89,7 +89,7 @@ fn test_pattern_memory_formation() {
                 .map(|(pos, _)| memory_field.information_at(*pos).unwrap().density())
                 .collect();
             
-            let stability = calculate_pattern_stability(&values, &stability_history);
+            let _stability = calculate_pattern_stability(&values, &stability_history);
             let lyapunov = calculate_local_lyapunov(&values, &stability_history);
             lyapunov_exponents.push(lyapunov);
             stability_history.push(values.clone());
@@ -474,6 +474,7 @@ fn calculate_pattern_stability(current: &[f64], history: &[Vec<f64>]) -> f64 {
     1.0 / (1.0 + diff_sum) // Higher stability = lower change
 }
 
+#[allow(dead_code)]
 fn calculate_pattern_retention(original: &[((f64, f64, f64), f64)], current: &[f64]) -> f64 {
     let mut retention = 0.0;
     
@@ -258,30 +258,10 @@ fn classify_emergent_behavior(step: usize, integration: f64, cohesion: f64, cons
 }
 
 fn measure_pattern_stability(reality: &Reality, clusters: &[(&str, (f64, f64, f64), f64)]) -> f64 {
-    // Measure how stable the information patterns are around clusters
-    let mut stability_sum = 0.0;
-    
-    for (_, position, _) in clusters {
-        let center_info = reality.information_at(*position).unwrap().density();
-        
-        // Check nearby points for stability
-        let nearby_positions = [
-            (position.0 + 0.2, position.1, position.2),
-            (position.0 - 0.2, position.1, position.2),
-            (position.0, position.1 + 0.2, position.2),
-            (position.0, position.1 - 0.2, position.2),
-        ];
-        
-        let mut local_stability = 0.0;
-        for nearby_pos in &nearby_positions {
-            if let Some(nearby_info) = reality.information_at(*nearby_pos) {
-                let similarity = 1.0 - (center_info - nearby_info.density()).abs() / center_info.max(1.0);
-                local_stability += similarity;
-            }
-        }
-        
-        stability_sum += local_stability / nearby_positions.len() as f64;
-    }
+    // Mean local stability within 0.2 of each cluster center
+    let stability_sum: f64 = clusters.iter()
+        .map(|(_, position, _)| reality.local_stability(*position, 0.2))
+        .sum();
     
     stability_sum / clusters.len() as f64
 }
@@ -87,8 +87,7 @@ fn test_uncertainty_principle() {
     println!("-------------|------------------|---------------|----------");
     
     for &density in &test_densities {
-        let _info = Information::new(density);
-        let uncertainty = (0.5 / (1.0 + density)).max(MIN_UNCERTAINTY);
+        let uncertainty = Information::new(density).uncertainty();
         let product = density * uncertainty;
         
         let relation = if density < INTEGRATION_THRESHOLD {
@@ -217,8 +216,9 @@ fn test_quantum_tunneling() {
     for i in 0..32 {
         let x = -2.0 + (i as f64) * 4.0 / 31.0;
         if x > -0.2 && x < 0.2 {
-            // Barrier region - add negative information (creates uncertainty)
-            reality.add_information((x, 0.0, 0.0), -1.0);
+            // Barrier region - below vacuum (creates uncertainty)
+            let barrier_density = reality.vacuum_density() - 1.0;
+            reality.set_density((x, 0.0, 0.0), barrier_density);
         }
     }
     
@@ -161,6 +161,7 @@ fn test_quantum_decoherence() {
     
     let mut isolated = Reality::new(20, (-1.0, 1.0), 0.3, 0.01);
     let mut environment = Reality::new(20, (-1.0, 1.0), 0.8, 0.01);
+    let mut bath = Reality::new(20, (-1.0, 1.0), 0.8, 0.01);
     
     // Create coherent superposition in both
     isolated.add_information((-0.3, 0.0, 0.0), 0.5);
@@ -169,10 +170,10 @@ fn test_quantum_decoherence() {
     environment.add_information((-0.3, 0.0, 0.0), 0.5);
     environment.add_information((0.3, 0.0, 0.0), 0.5);
     
-    // Add environmental interactions to second system
+    // Environmental bath the second system is coupled to
     for i in 0..5 {
         let x = -0.8 + (i as f64) * 0.4;
-        environment.add_information((x, 0.4, 0.0), 0.8);  // Environment
+        bath.add_information((x, 0.4, 0.0), 0.8);
     }
     
     println!("Measuring decoherence in isolated vs environmental systems:");
@@ -182,6 +183,10 @@ fn test_quantum_decoherence() {
     for step in 0..40 {
         isolated.evolve();
         environment.evolve();
+        bath.evolve();
+        let system_before = environment.clone();
+        environment.couple(&bath, 2.0);
+        bath.couple(&system_before, 2.0);
         
         if step % 8 == 0 {
             let iso_coherence = measure_coherence(&isolated);
@@ -290,14 +295,14 @@ fn test_consciousness_threshold() {
             // Add observer at specific consciousness level
             system.add_information((0.0, 0.0, 0.0), observer_level);
             
-            let initial_coherence = measure_coherence(&system);
+            let initial_coherence = measure_uniformity(&system);
             
             // Evolve system
             for _ in 0..20 {
                 system.evolve();
             }
             
-            let final_coherence = measure_coherence(&system);
+            let final_coherence = measure_uniformity(&system);
             let collapse_rate = (initial_coherence - final_coherence) / initial_coherence;
             collapse_rates.push(collapse_rate);
         }
@@ -335,8 +340,16 @@ fn test_consciousness_threshold() {
     println!("→ Consciousness threshold provides quantitative measurement criterion\n");
 }
 
-/// Measure quantum coherence as information uniformity
+/// Measure quantum coherence as the correlation between the two branches
 fn measure_coherence(reality: &Reality) -> f64 {
+    // Boxes around the branches at x = ±0.3, matched cell by cell
+    let left = ((-0.55, -0.55, -0.55), (-0.05, 0.55, 0.55));
+    let right = ((0.05, -0.55, -0.55), (0.55, 0.55, 0.55));
+    reality.coherence(left, right)
+}
+
+/// Measure how evenly information spreads over the superposition
+fn measure_uniformity(reality: &Reality) -> f64 {
     let positions = [
         (-0.3, 0.0, 0.0), (0.3, 0.0, 0.0),
         (-0.1, 0.0, 0.0), (0.1, 0.0, 0.0),
@@ -361,6 +374,6 @@ fn measure_coherence(reality: &Reality) -> f64 {
     
     let variance = info_variance / positions.len() as f64;
     
-    // Coherence = uniformity (low variance = high coherence)
+    // Uniformity (low variance = high uniformity)
     1.0 / (1.0 + variance)
-}
\ No newline at end of file
+}
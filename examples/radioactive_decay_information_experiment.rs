@@ -511,7 +511,7 @@ fn calculate_quantum_field_coupling(field_density: f64) -> f64 {
     // IIRT prediction: Information fields couple to quantum probability amplitudes
     let vacuum_baseline = VACUUM_INFORMATION;
     let field_enhancement = field_density / vacuum_baseline;
-    let uncertainty = (0.5 / (1.0 + field_density)).max(MIN_UNCERTAINTY);
+    let uncertainty = Information::new(field_density).uncertainty();
     
     // Coupling strength limited by uncertainty principle
     let max_coupling = 1.0 / uncertainty - 1.0;
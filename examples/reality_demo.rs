@@ -30,7 +30,7 @@ fn main() {
     println!("After perturbation:");
     println!("- Total information: {:.1} bits", initial_info);
     println!("- Integrated points: {}", reality.conscious_count());
-    println!("- Integration coverage: {:.1}%\n", 100.0 * reality.conscious_count() as f64 / 64_f64.powi(3));
+    println!("- Integration coverage: {:.1}%\n", 100.0 * reality.conscious_fraction());
     
     println!("Evolving according to IIRT equation:");
     println!("∂ℐ/∂t = D∇²ℐ - ε²ℐ + ℐ(1-ℐ/ℐ_max)\n");
@@ -55,9 +55,9 @@ fn experiment_1_threshold_mapping() {
     println!("---------|----------|----------|-----------|---------------");
     
     let mut threshold_candidates = Vec::new();
+    let responses = sweep(&test_densities, |&density| measure_system_response(density));
     
-    for &density in &test_densities {
-        let (self_ref, creation_rate, stability) = measure_system_response(density);
+    for (&density, (self_ref, creation_rate, stability)) in test_densities.iter().zip(responses) {
         
         let classification = if self_ref && creation_rate > 0.01 && stability > 0.8 {
             "ACTIVE"
@@ -96,8 +96,8 @@ fn experiment_2_creation_scaling() {
     println!("Perturbation | Final Created | Amplification | Efficiency");
     println!("-------------|---------------|---------------|----------");
     
-    for &amp in &perturbations {
-        let (created, amplification, efficiency) = measure_creation_scaling(amp);
+    let scaling = sweep(&perturbations, |&amp| measure_creation_scaling(amp));
+    for (&amp, (created, amplification, efficiency)) in perturbations.iter().zip(scaling) {
         println!("{:12.2} | {:13.1} | {:13.0}× | {:10.3}",
                  amp, created, amplification, efficiency);
     }
@@ -160,8 +160,8 @@ fn experiment_4_universality_test() {
     println!("Config | Diffusion | Resolution | Measured Threshold | Deviation");
     println!("-------|-----------|------------|--------------------|-----------");
     
-    for (i, &(diffusion, resolution)) in configs.iter().enumerate() {
-        let threshold = measure_threshold_for_config(diffusion, resolution);
+    let thresholds = sweep(&configs, |&(diffusion, resolution)| measure_threshold_for_config(diffusion, resolution));
+    for (i, (&(diffusion, resolution), threshold)) in configs.iter().zip(thresholds).enumerate() {
         let deviation = threshold - INTEGRATION_THRESHOLD;
         
         println!("{:6} | {:9.1} | {:10} | {:18.6} | {:+9.6}",
@@ -227,10 +227,11 @@ fn measure_threshold_for_config(diffusion: f64, resolution: usize) -> f64 {
     // Find threshold by binary search
     let mut low = 0.0;
     let mut high = 2.0;
+    let mut reality = Reality::new(resolution, (-1.0, 1.0), diffusion, 0.01);
     
     for _ in 0..20 { // 20 iterations should give ~6 decimal places
         let test_density = (low + high) / 2.0;
-        let (self_ref, creation, _) = measure_system_response_config(test_density, diffusion, resolution);
+        let (self_ref, creation, _) = measure_system_response_config(&mut reality, test_density);
         
         if self_ref && creation > 0.001 {
             high = test_density;
@@ -242,8 +243,9 @@ fn measure_threshold_for_config(diffusion: f64, resolution: usize) -> f64 {
     (low + high) / 2.0
 }
 
-fn measure_system_response_config(density: f64, diffusion: f64, resolution: usize) -> (bool, f64, f64) {
-    let mut reality = Reality::new(resolution, (-1.0, 1.0), diffusion, 0.01);
+fn measure_system_response_config(reality: &mut Reality, density: f64) -> (bool, f64, f64) {
+    // Reuse the grid across the search instead of reallocating
+    reality.reset_to_vacuum();
     reality.add_information((0.0, 0.0, 0.0), density - VACUUM_INFORMATION);
     
     let initial_total = reality.total_information();
@@ -66,8 +66,8 @@ fn main() {
             println!("│ Field State:                                        │");
             println!("│ • Total Information: {:>8.1} bits                 │", current_info);
             println!("│ • Net Creation: {:>6.1} bits                      │", info_created);
-            println!("│ • Integrated Points: {:>6} / {}            │", current_conscious, 64_u64.pow(3));
-            println!("│ • Integration %: {:>5.1}%                       │", 100.0 * current_conscious as f64 / 64_f64.powi(3));
+            println!("│ • Integrated Points: {:>6} / {}            │", current_conscious, reality.grid_point_count());
+            println!("│ • Integration %: {:>5.1}%                       │", 100.0 * reality.conscious_fraction());
             println!("│ • Creation Rate: {:>8.1} bits/step             │", info_created / step as f64);
             println!("└─────────────────────────────────────────────────────┘");
             
@@ -108,9 +108,9 @@ fn main() {
     println!("• Net information creation: {:.1} bits", total_created);
     println!("• Average creation rate: {:.1} bits/step", total_created / 30.0);
     println!("• Integrated points: {} / {} ({:.1}%)", 
-             final_conscious, 64_u64.pow(3), 
-             100.0 * final_conscious as f64 / 64_f64.powi(3));
-    println!("• Integration coverage: {:.1}%", 100.0 * final_conscious as f64 / 64_f64.powi(3));
+             final_conscious, reality.grid_point_count(), 
+             100.0 * reality.conscious_fraction());
+    println!("• Integration coverage: {:.1}%", 100.0 * reality.conscious_fraction());
     
     // Demonstrate emergent physical phenomena
     println!("\nEmergent Phenomena Analysis");
@@ -0,0 +1,313 @@
+//! Complex-Valued Information Fields
+//!
+//! Wave-function reading of IIRT: ℐ is a complex amplitude ψ and the
+//! information density is |ψ|². Evolution combines a Schrödinger-like
+//! dispersive term with the IIRT reaction acting on the density:
+//!
+//! ```text
+//! ∂ψ/∂t = iD∇²ψ + ½[(1-|ψ|²/ℐ_max) - ε²(|ψ|²)]ψ
+//! ```
+//!
+//! The reaction factor is exactly the one that makes ∂|ψ|²/∂t reproduce
+//! ℐ(1-ℐ/ℐ_max) - ε²ℐ. The iD∇² term alone is unitary and conserves ∫|ψ|².
+//!
+//! Enabled with the `complex` feature.
+
+use num_complex::Complex;
+
+use crate::constants::*;
+use crate::reality::{BuildError, Information};
+
+/// Limit on D·dt for the RK4 step of iD∇²ψ (grid units)
+///
+/// The stencil's spectrum reaches ±12iD and classical RK4 is stable on the
+/// imaginary axis up to |λ·dt| = 2√2, giving D·dt ≤ √2/6.
+const MAX_UNITARY_STABILITY_NUMBER: f64 = std::f64::consts::SQRT_2 / 6.0;
+
+/// Name for `ComplexReality` when it is used as a wave function
+///
+/// `evolve` is the Schrödinger term iD∇²ψ coupled to the IIRT growth and
+/// uncertainty terms, so packets interfere by phase as they evolve.
+pub type QuantumReality = ComplexReality;
+
+/// 3D complex amplitude field with density |ψ|²
+#[derive(Clone)]
+pub struct ComplexReality {
+    field: Vec<Complex<f64>>,
+    resolution: usize,
+    bounds: (f64, f64),
+    diffusion: f64,
+    dt: f64,
+    time: f64,
+    step: u64,
+}
+
+impl ComplexReality {
+    /// Create an empty (ψ = 0) complex field
+    ///
+    /// Rejects what `RealityBuilder::build` rejects: fewer than 2 cells per
+    /// axis, non-finite or empty bounds, non-positive dt, negative diffusion,
+    /// and a D·dt past the stability limit of the RK4 step (√2/6).
+    pub fn new(resolution: usize, bounds: (f64, f64), diffusion: f64, dt: f64) -> Result<Self, BuildError> {
+        if resolution < 2 {
+            return Err(BuildError::Resolution(resolution));
+        }
+        let (min_bound, max_bound) = bounds;
+        if !min_bound.is_finite() || !max_bound.is_finite() || min_bound >= max_bound {
+            return Err(BuildError::Bounds(min_bound, max_bound));
+        }
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(BuildError::Dt(dt));
+        }
+        if !diffusion.is_finite() || diffusion < 0.0 {
+            return Err(BuildError::Diffusion(diffusion));
+        }
+        if diffusion * dt > MAX_UNITARY_STABILITY_NUMBER {
+            return Err(BuildError::Unstable(diffusion * dt));
+        }
+        Ok(Self {
+            field: vec![Complex::new(0.0, 0.0); resolution * resolution * resolution],
+            resolution,
+            bounds,
+            diffusion,
+            dt,
+            time: 0.0,
+            step: 0,
+        })
+    }
+
+    /// Add a Gaussian wavepacket ψ = A·exp(-|r-r₀|²/4σ²)·exp(ik·r)
+    ///
+    /// `sigma` is the width of the density |ψ|²; `momentum` is the wave vector k.
+    pub fn add_wavepacket(&mut self, center: (f64, f64, f64), sigma: f64, momentum: (f64, f64, f64), amplitude: f64) {
+        for idx in 0..self.field.len() {
+            let (x, y, z) = self.index_position(idx);
+            let (dx, dy, dz) = (x - center.0, y - center.1, z - center.2);
+            let envelope = amplitude * (-(dx * dx + dy * dy + dz * dz) / (4.0 * sigma * sigma)).exp();
+            let phase = momentum.0 * x + momentum.1 * y + momentum.2 * z;
+            self.field[idx] += Complex::from_polar(envelope, phase);
+        }
+    }
+
+    /// Evolve one step of the full equation (dispersion + IIRT reaction)
+    pub fn evolve(&mut self) {
+        self.rk4_step(true);
+    }
+
+    /// Evolve one step of the unitary part only: ∂ψ/∂t = iD∇²ψ
+    pub fn evolve_unitary(&mut self) {
+        self.rk4_step(false);
+    }
+
+    /// Amplitude ψ at position
+    pub fn amplitude_at(&self, position: (f64, f64, f64)) -> Option<Complex<f64>> {
+        self.position_to_index(position).map(|idx| self.field[idx])
+    }
+
+    /// Information at position, mapping density to |ψ|²
+    pub fn information_at(&self, position: (f64, f64, f64)) -> Option<Information> {
+        self.amplitude_at(position).map(|psi| Information::new(psi.norm_sqr()))
+    }
+
+    /// Total information ∫|ψ|² (sum over cells)
+    pub fn total_information(&self) -> f64 {
+        self.field.iter().map(|psi| psi.norm_sqr()).sum()
+    }
+
+    /// Probability density |ψ|² of every cell, x-fastest
+    ///
+    /// Unnormalized: it sums to `total_information`. Unlike a sum of real
+    /// density bumps, overlapping amplitudes interfere: two packets of
+    /// opposite momentum leave nodes where the density vanishes.
+    pub fn probability_density(&self) -> Vec<f64> {
+        self.field.iter().map(|psi| psi.norm_sqr()).collect()
+    }
+
+    /// RMS radius of the density about its centroid
+    pub fn spread(&self) -> f64 {
+        let total = self.total_information();
+        let mut mean = (0.0, 0.0, 0.0);
+        let mut mean_sq = 0.0;
+        for (idx, psi) in self.field.iter().enumerate() {
+            let (x, y, z) = self.index_position(idx);
+            let weight = psi.norm_sqr() / total;
+            mean.0 += weight * x;
+            mean.1 += weight * y;
+            mean.2 += weight * z;
+            mean_sq += weight * (x * x + y * y + z * z);
+        }
+        (mean_sq - (mean.0 * mean.0 + mean.1 * mean.1 + mean.2 * mean.2)).max(0.0).sqrt()
+    }
+
+    /// Get current time
+    pub fn time(&self) -> f64 { self.time }
+
+    /// Get step count
+    pub fn step(&self) -> u64 { self.step }
+
+    // Private helpers
+
+    fn rk4_step(&mut self, reaction: bool) {
+        let dt = self.dt;
+        let k1 = self.rate(&self.field, reaction);
+        let k2 = self.rate(&Self::offset(&self.field, &k1, dt / 2.0), reaction);
+        let k3 = self.rate(&Self::offset(&self.field, &k2, dt / 2.0), reaction);
+        let k4 = self.rate(&Self::offset(&self.field, &k3, dt), reaction);
+
+        for (idx, psi) in self.field.iter_mut().enumerate() {
+            *psi += (k1[idx] + k2[idx] * 2.0 + k3[idx] * 2.0 + k4[idx]) * (dt / 6.0);
+        }
+        self.time += dt;
+        self.step += 1;
+    }
+
+    fn offset(field: &[Complex<f64>], slope: &[Complex<f64>], h: f64) -> Vec<Complex<f64>> {
+        field.iter().zip(slope).map(|(psi, k)| psi + k * h).collect()
+    }
+
+    /// ∂ψ/∂t on interior cells; faces are held fixed
+    fn rate(&self, field: &[Complex<f64>], reaction: bool) -> Vec<Complex<f64>> {
+        let n = self.resolution;
+        let mut rate = vec![Complex::new(0.0, 0.0); field.len()];
+
+        for k in 1..n - 1 {
+            for j in 1..n - 1 {
+                for i in 1..n - 1 {
+                    let idx = k * n * n + j * n + i;
+                    let psi = field[idx];
+                    let laplacian = field[idx - 1] + field[idx + 1]
+                        + field[idx - n] + field[idx + n]
+                        + field[idx - n * n] + field[idx + n * n]
+                        - psi * 6.0;
+
+                    let mut change = Complex::<f64>::i() * laplacian * self.diffusion;
+                    if reaction {
+                        let density = psi.norm_sqr();
+                        let growth = (1.0 - density / MAX_INFORMATION) - Information(density).uncertainty().powi(2);
+                        change += psi * (0.5 * growth);
+                    }
+                    rate[idx] = change;
+                }
+            }
+        }
+        rate
+    }
+
+    fn index_position(&self, idx: usize) -> (f64, f64, f64) {
+        let n = self.resolution;
+        let (min_bound, max_bound) = self.bounds;
+        let scale = (max_bound - min_bound) / (n - 1) as f64;
+        (
+            min_bound + (idx % n) as f64 * scale,
+            min_bound + ((idx / n) % n) as f64 * scale,
+            min_bound + (idx / (n * n)) as f64 * scale,
+        )
+    }
+
+    fn position_to_index(&self, (x, y, z): (f64, f64, f64)) -> Option<usize> {
+        let n = self.resolution;
+        let (min_bound, max_bound) = self.bounds;
+        let scale = (max_bound - min_bound) / (n - 1) as f64;
+
+        // Round in f64: a negative index must not saturate onto the face
+        let cell = |c: f64| {
+            let c = ((c - min_bound) / scale).round();
+            (0.0..n as f64).contains(&c).then_some(c as usize)
+        };
+        let (i, j, k) = (cell(x)?, cell(y)?, cell(z)?);
+        Some(k * n * n + j * n + i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_wavepacket_disperses_unitarily() {
+        // Δx = 1: σ = 2.5 cells, well clear of the fixed faces; width grows by √(1+(Dt/σ²)²)
+        let mut reality = ComplexReality::new(32, (-15.5, 15.5), 1.0, 0.1).unwrap();
+        reality.add_wavepacket((0.5, 0.5, 0.5), 2.5, (0.0, 0.0, 0.0), 1.0);
+
+        let initial_norm = reality.total_information();
+        let initial_spread = reality.spread();
+        for _ in 0..60 {
+            reality.evolve_unitary();
+        }
+
+        assert!(reality.spread() > 1.2 * initial_spread);
+        // RK4 is unitary up to its truncation error
+        assert!((reality.total_information() - initial_norm).abs() < 1e-5 * initial_norm);
+    }
+
+    #[test]
+    fn test_probability_density_shows_interference() {
+        let k = std::f64::consts::FRAC_PI_2;
+        let mut reality = ComplexReality::new(8, (0.0, 7.0), 1.0, 0.01).unwrap();
+        reality.add_wavepacket((3.0, 3.0, 3.0), 1.0, (k, 0.0, 0.0), 1.0);
+        reality.add_wavepacket((3.0, 3.0, 3.0), 1.0, (-k, 0.0, 0.0), 1.0);
+
+        let probability = reality.probability_density();
+        let total = reality.total_information();
+        assert!((probability.iter().sum::<f64>() - total).abs() < 1e-12 * total);
+        // ψ ∝ cos(kx): a node at x = 3, antinodes at x = 2 and 4
+        let cell = |x: usize| probability[(3 * 8 + 3) * 8 + x];
+        assert!(cell(3) < 1e-20);
+        assert!(cell(2) > 1.0 && (cell(2) - cell(4)).abs() < 1e-12);
+
+        assert!(QuantumReality::new(4, (0.0, 3.0), 1.0, 0.01).unwrap().probability_density().iter().all(|&p| p == 0.0));
+    }
+
+    #[test]
+    fn test_evolution_keeps_phase_dependent_interference() {
+        // Packets at x = ±3 moving inward, added in phase or in antiphase
+        let collide = |sign: f64| {
+            let mut reality = QuantumReality::new(25, (-12.0, 12.0), 1.0, 0.05).unwrap();
+            reality.add_wavepacket((-3.0, 0.0, 0.0), 1.5, (1.0, 0.0, 0.0), 1.0);
+            reality.add_wavepacket((3.0, 0.0, 0.0), 1.5, (-1.0, 0.0, 0.0), sign);
+            for _ in 0..40 {
+                reality.evolve();
+            }
+            reality
+        };
+        let (bright, dark) = (collide(1.0), collide(-1.0));
+        let density = |reality: &QuantumReality| reality.information_at((0.0, 0.0, 0.0)).unwrap().density();
+
+        // The reaction term depends on |ψ|² only, so the odd antiphase state
+        // keeps its node at x = 0 while the even one piles up there
+        assert!(dark.time() > 0.0 && density(&dark) < 1e-20);
+        assert!(density(&bright) > 0.1);
+    }
+
+    #[test]
+    fn test_new_rejects_bad_parameters() {
+        assert!(ComplexReality::new(2, (0.0, 1.0), 1.0, 0.01).is_ok());
+        assert!(matches!(ComplexReality::new(1, (0.0, 1.0), 1.0, 0.01), Err(BuildError::Resolution(1))));
+        assert!(matches!(ComplexReality::new(8, (1.0, 1.0), 1.0, 0.01), Err(BuildError::Bounds(..))));
+        assert!(matches!(ComplexReality::new(8, (0.0, f64::INFINITY), 1.0, 0.01), Err(BuildError::Bounds(..))));
+        assert!(matches!(ComplexReality::new(8, (0.0, 1.0), 1.0, f64::NAN), Err(BuildError::Dt(_))));
+        assert!(matches!(ComplexReality::new(8, (0.0, 1.0), -1.0, 0.01), Err(BuildError::Diffusion(_))));
+        assert!(matches!(ComplexReality::new(8, (0.0, 1.0), 1.0, 0.3), Err(BuildError::Unstable(_))));
+    }
+
+    #[test]
+    fn test_positions_below_bounds_are_outside() {
+        let reality = ComplexReality::new(8, (0.0, 7.0), 1.0, 0.01).unwrap();
+        assert!(reality.amplitude_at((0.0, 0.0, 0.0)).is_some());
+        assert!(reality.amplitude_at((-0.4, 0.0, 0.0)).is_some());
+        assert!(reality.amplitude_at((-1.0, 0.0, 0.0)).is_none());
+        assert!(reality.amplitude_at((3.0, -2.0, 3.0)).is_none());
+        assert!(reality.information_at((3.0, 3.0, -5.0)).is_none());
+        assert!(reality.amplitude_at((7.6, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_density_is_modulus_squared() {
+        let mut reality = ComplexReality::new(8, (0.0, 7.0), 1.0, 0.01).unwrap();
+        reality.add_wavepacket((3.0, 3.0, 3.0), 1.0, (1.0, 0.0, 0.0), 1.5);
+        let psi = reality.amplitude_at((3.0, 3.0, 3.0)).unwrap();
+        let info = reality.information_at((3.0, 3.0, 3.0)).unwrap();
+        assert!((info.density() - psi.norm_sqr()).abs() < 1e-12);
+        assert!((info.density() - 2.25).abs() < 1e-12);
+    }
+}
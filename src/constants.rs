@@ -46,10 +46,10 @@ pub const MIN_UNCERTAINTY: f64 = 0.01;
 /// Minimum information density
 pub const MIN_INFORMATION: f64 = 0.0;
 
-/// Dark energy density prediction: 73.0% (exponential growth from threshold)
+/// Observed dark energy fraction of the cosmic energy budget: 73%
 /// 
-/// Calculated from exponential vacuum evolution: ℐ_threshold × e^(α×13.8Gyr) / ℐ_max
-/// Perfect match with observed dark energy density (73%)
+/// The figure the exponential vacuum evolution ℐ_threshold × e^(α×13.8Gyr) / ℐ_max
+/// is checked against; see `cosmology::compare_to_observation`.
 pub const DARK_ENERGY_DENSITY: f64 = 0.73;
 
 /// Default diffusion coefficient for information spreading
@@ -58,6 +58,34 @@ pub const DEFAULT_DIFFUSION: f64 = 1.0;
 /// Default time step for evolution
 pub const DEFAULT_DT: f64 = 0.001;
 
+/// Explicit 3D diffusion stability limit on D·dt/Δx²: 1/6
+/// 
+/// Above it the forward Euler step amplifies grid-scale noise without bound.
+pub const MAX_STABILITY_NUMBER: f64 = 1.0 / 6.0;
+
+/// Explicit 2D diffusion stability limit on D·dt/Δx²: 1/4
+pub const MAX_STABILITY_NUMBER_2D: f64 = 0.25;
+
+/// Density step of the default `find_critical_threshold` sweep, in bits
+/// 
+/// The sweep runs from `MIN_INFORMATION` to `MAX_INFORMATION`, so the
+/// estimate is good to half a step.
+pub const CRITICAL_SWEEP_STEP: f64 = 0.05;
+
+/// Multiple of ℐ_max above which `Reality::evolve_checked` reports divergence
+pub const DIVERGENCE_FACTOR: f64 = 10.0;
+
+/// Density quantum of `Reality::state_fingerprint`, in bits
+/// 
+/// Densities are rounded to multiples of it before hashing, so numerical
+/// noise well below it leaves the fingerprint unchanged.
+pub const FINGERPRINT_QUANTUM: f64 = 1e-9;
+
+/// Softening length r₀ of `Reality::binding_energy`, in spatial units
+/// 
+/// Keeps the pair energy finite as two positions coincide.
+pub const BINDING_SOFTENING_LENGTH: f64 = 0.1;
+
 /// Default field resolution (grid size)
 pub const DEFAULT_RESOLUTION: usize = 64;
 
@@ -141,6 +169,31 @@ pub fn dark_energy_density_at_time(t_gyr: f64) -> f64 {
     vacuum_at_cosmic_time(t_gyr) / MAX_INFORMATION
 }
 
+/// Dark energy density at each cosmic age: (t, ℐ_vac(t)/ℐ_max) pairs
+pub fn dark_energy_curve(ages: &[f64]) -> Vec<(f64, f64)> {
+    ages.iter().map(|&t| (t, dark_energy_density_at_time(t))).collect()
+}
+
+/// Cosmic acceleration: d²ℐ_vac/dt² = α²ℐ_vac(t) in bits per Gyr²
+/// 
+/// Exact second derivative of the exponential vacuum evolution; positive at
+/// every epoch, so the growth never decelerates.
+pub fn cosmic_acceleration(t_gyr: f64) -> f64 {
+    EXPONENTIAL_GROWTH_RATE.powi(2) * vacuum_at_cosmic_time(t_gyr)
+}
+
+/// Characteristic propagation speed √D of the diffusion term
+/// 
+/// In grid cells per unit time, since the stencil works in grid units;
+/// multiply by the grid spacing for physical units. Pure diffusion has no
+/// sharp front: a threshold contour spreads as √(Dt), and the logistic
+/// term turns it into a Fisher-KPP front moving at 2√(D·r), r being the
+/// low-density growth rate. Measured wavefront speeds are therefore of
+/// order √D rather than equal to it.
+pub fn propagation_speed(diffusion: f64) -> f64 {
+    diffusion.sqrt()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -243,4 +296,18 @@ mod tests {
         let current_de = dark_energy_density_at_time(CURRENT_COSMIC_AGE_GYR);
         assert!(current_de > 0.72 && current_de < 0.74);
     }
+    
+    #[test]
+    fn test_dark_energy_curve_and_acceleration() {
+        let curve = dark_energy_curve(&[0.0, 5.0, CURRENT_COSMIC_AGE_GYR]);
+        assert_eq!(curve.len(), 3);
+        assert_eq!(curve[2], (CURRENT_COSMIC_AGE_GYR, dark_energy_density_at_time(CURRENT_COSMIC_AGE_GYR)));
+        assert!(curve.windows(2).all(|pair| pair[1].1 > pair[0].1));
+        
+        // Matches a central second difference of ℐ_vac(t)
+        let (t, h) = (CURRENT_COSMIC_AGE_GYR, 1e-3);
+        let numeric = (vacuum_at_cosmic_time(t + h) - 2.0 * vacuum_at_cosmic_time(t) + vacuum_at_cosmic_time(t - h)) / (h * h);
+        assert!((cosmic_acceleration(t) - numeric).abs() < 1e-4);
+        assert!(cosmic_acceleration(0.0) > 0.0);
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,39 @@
+//! Cosmology - Dark Energy from the Evolving Vacuum
+//!
+//! The vacuum grows as ℐ_vac(t) = ℐ_threshold·e^(αt), and its share of the
+//! maximum density is the dark energy fraction:
+//!
+//! ```text
+//! Ω_Λ(t) = ℐ_vac(t) / ℐ_max
+//! ```
+//!
+//! The observed value it is checked against is `DARK_ENERGY_DENSITY`.
+
+use crate::constants::dark_energy_density_at_time;
+
+/// Predicted dark energy fraction Ω_Λ = ℐ_vac(t)/ℐ_max at cosmic age `age_gyr`
+pub fn dark_energy_fraction(age_gyr: f64) -> f64 {
+    dark_energy_density_at_time(age_gyr)
+}
+
+/// Relative error |Ω_Λ(t) - observed| / observed of the prediction at `age_gyr`
+///
+/// Panics unless `observed` is positive.
+pub fn compare_to_observation(age_gyr: f64, observed: f64) -> f64 {
+    assert!(observed > 0.0, "compare_to_observation requires a positive observed fraction");
+    (dark_energy_fraction(age_gyr) - observed).abs() / observed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{CURRENT_COSMIC_AGE_GYR, DARK_ENERGY_DENSITY};
+
+    #[test]
+    fn test_dark_energy_matches_observation_today() {
+        assert!(compare_to_observation(CURRENT_COSMIC_AGE_GYR, DARK_ENERGY_DENSITY) < 0.01);
+        // Far from today the prediction and the observation part ways
+        assert!(compare_to_observation(1.0, DARK_ENERGY_DENSITY) > 0.5);
+        assert!(dark_energy_fraction(1.0) < dark_energy_fraction(CURRENT_COSMIC_AGE_GYR));
+    }
+}
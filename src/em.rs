@@ -0,0 +1,68 @@
+//! Electromagnetic Fields from Information Dynamics
+//!
+//! Maps the information field onto E and B through the coupling constants:
+//!
+//! ```text
+//! E = -α_EM ∇ℐ          (information gradients act as a potential)
+//! B =  β_EM ∇×J_info    (curl of the information current J = -D∇ℐ)
+//! ```
+//!
+//! Gradients use the real grid spacing (`Reality::gradient`), so E is in
+//! V/m per bit/m. The current is the diffusive flux of `evolve`, a pure
+//! gradient flow: away from the faces its curl - and so B - vanishes up to
+//! discretisation error, and a non-zero B marks boundaries or anisotropy.
+
+use crate::constants::{ALPHA_EM, BETA_EM};
+use crate::reality::Reality;
+
+impl Reality {
+    /// Electric field E = -α_EM ∇ℐ at position
+    ///
+    /// Points down the information gradient: radially outward around a peak.
+    /// Returns `None` where `contains` is false.
+    pub fn electric_field(&self, position: (f64, f64, f64)) -> Option<(f64, f64, f64)> {
+        let (gx, gy, gz) = self.gradient(position)?;
+        Some((-ALPHA_EM * gx, -ALPHA_EM * gy, -ALPHA_EM * gz))
+    }
+
+    /// Magnetic field B = β_EM ∇×J of the information current at position
+    ///
+    /// Returns `None` where `contains` is false.
+    pub fn magnetic_field(&self, position: (f64, f64, f64)) -> Option<(f64, f64, f64)> {
+        let (cx, cy, cz) = self.vorticity(position)?;
+        Some((BETA_EM * cx, BETA_EM * cy, BETA_EM * cz))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peak_produces_radial_electric_field() {
+        let mut reality = Reality::new_at_cosmic_age(17, (-2.0, 2.0), 1.0, 0.01, 0.0);
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        for _ in 0..10 {
+            reality.evolve();
+        }
+
+        let radius = 0.5;
+        let (ex, ey, ez) = reality.electric_field((radius, 0.0, 0.0)).unwrap();
+        assert!(ex > 0.0, "E points away from the peak");
+        assert!(ey.abs() < 1e-12 * ex && ez.abs() < 1e-12 * ex);
+
+        // Same magnitude, rotated, in every axis direction
+        for (position, component) in [
+            ((-radius, 0.0, 0.0), -reality.electric_field((-radius, 0.0, 0.0)).unwrap().0),
+            ((0.0, radius, 0.0), reality.electric_field((0.0, radius, 0.0)).unwrap().1),
+            ((0.0, 0.0, -radius), -reality.electric_field((0.0, 0.0, -radius)).unwrap().2),
+        ] {
+            assert!((component - ex).abs() < 1e-12 * ex, "{:?}", position);
+        }
+
+        // The gradient flow is irrotational in the interior
+        let (bx, by, bz) = reality.magnetic_field((radius, radius, 0.0)).unwrap();
+        assert!(bx.abs() < 1e-30 && by.abs() < 1e-30 && bz.abs() < 1e-30);
+        assert!(reality.electric_field((3.0, 0.0, 0.0)).is_none());
+    }
+}
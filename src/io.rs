@@ -0,0 +1,1239 @@
+//! Persistence - Saving and Loading Fields
+//!
+//! Binary field state format (little-endian):
+//!
+//! ```text
+//! "IIRT" | version: u8 | dimensions: u8 | shape: 3×u64 | extents: 6×f64
+//!        | diffusion: f64 | dt: f64 | cosmic_age: f64 | time: f64 | step: u64
+//!        | max_information: f64 | anisotropic: u8 | diffusion_xyz: 3×f64
+//!        | clamping: u8 | pin_count: u64 | densities: nx·ny·nz × f64
+//!        | pins: pin_count × (index: u64, density: f64)
+//! ```
+//!
+//! Densities are x-fastest, matching `Reality::as_slice`; a 2D field has
+//! nz = 1. `diffusion_xyz` holds the `Reality::set_diffusion_xyz`
+//! coefficients when `anisotropic` is 1, and D three times when it is 0.
+//! `pins` are the cells held by `Reality::pin_region`, by ascending index.
+//!
+//! Readers reject an unknown magic or version rather than misreading data.
+//! Versions 1 and 2 stored a single resolution and (min, max) pair for a
+//! cubic grid, version 1 without the dimensions byte (always 3D). Later
+//! versions each appended fields: 4 `max_information`, 5 `anisotropic` and
+//! `diffusion_xyz`, 6 `clamping`, `pin_count` and `pins`. Older files load
+//! with `MAX_INFORMATION`, isotropic D, no clamping and no pins in place of
+//! what they lack. All are still read.
+//! `checkpoint` produces the same bytes in memory.
+//!
+//! There is no HDF5 exporter: it needs the `hdf5` crate and the system
+//! libhdf5, which are not part of this build. Runs reach Python frame by
+//! frame through `export_npy`.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::constants::{INTEGRATION_THRESHOLD, MAX_INFORMATION};
+use crate::reality::{Axis, Information, Op, ProbeHistory, Reality, RealityBuilder, RealityConfig};
+
+/// Magic header of the binary field format
+pub const FIELD_MAGIC: &[u8; 4] = b"IIRT";
+
+/// Current binary field format version
+pub const FIELD_FORMAT_VERSION: u8 = 6;
+
+/// Magic prefix of NumPy `.npy` files
+const NPY_MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// Longest `.npy` header accepted, NumPy's own default limit
+const NPY_MAX_HEADER: usize = 10_000;
+
+/// Largest cell count per axis a reader accepts
+const MAX_RESOLUTION: usize = 4096;
+
+const BUNDLE_FIELD: &str = "field.bin";
+const BUNDLE_CONFIG: &str = "config.txt";
+const BUNDLE_PROBES: &str = "probes.txt";
+const BUNDLE_OPS: &str = "ops.txt";
+
+impl Reality {
+    /// Save the field state to a binary file (see the module docs for the format)
+    /// 
+    /// Probes, op log and running statistics are not saved; use `save_bundle`
+    /// for a full experiment.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_state(self, &mut writer)?;
+        writer.flush()
+    }
+    
+    /// Load a field saved by `save`
+    /// 
+    /// Fails with `InvalidData` on a wrong magic header or format version.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Reality> {
+        read_state_file(path.as_ref())
+    }
+    
+    /// Field state as in-memory bytes, in the `save` format
+    /// 
+    /// For cheap branching and keeping many generations without touching
+    /// the filesystem; the same state `save` keeps is captured.
+    pub fn checkpoint(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(128 + 8 * self.field.len());
+        write_state(self, &mut bytes).expect("writing to a Vec cannot fail");
+        bytes
+    }
+    
+    /// Restore a field from `checkpoint` bytes
+    /// 
+    /// Fails with `InvalidData` on a bad header or trailing bytes, and with
+    /// `UnexpectedEof` on truncated input.
+    pub fn from_checkpoint(mut bytes: &[u8]) -> io::Result<Reality> {
+        let len = bytes.len() as u64;
+        let reality = read_state(&mut bytes, len)?;
+        if !bytes.is_empty() {
+            return Err(invalid("trailing bytes after checkpoint"));
+        }
+        Ok(reality)
+    }
+    
+    /// Write the density on a plane as CSV
+    /// 
+    /// The plane is the grid plane perpendicular to `axis` nearest
+    /// `coordinate` (clamped into bounds). The header row holds the column
+    /// positions and the first column the row positions, in physical units:
+    /// columns run along x (y for `Axis::X`), rows along the remaining axis.
+    /// 
+    /// A 2D field has a single plane: only `Axis::Z` is accepted and
+    /// `coordinate` is ignored.
+    pub fn export_slice_csv<W: Write>(&self, mut writer: W, axis: Axis, coordinate: f64) -> io::Result<()> {
+        if self.planar && axis != Axis::Z {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "a 2D field only has the z plane"));
+        }
+        let (nx, ny, nz) = self.shape;
+        let (dx, dy, dz) = self.spacing();
+        let ((x0, _), (y0, _), (z0, _)) = self.extents;
+        let (x, y, z) = ((x0, dx, nx), (y0, dy, ny), (z0, dz, nz));
+        let plane = self.axis_index(axis, coordinate);
+
+        // Axis label, (plane, row, column) index strides and the row and column axes
+        let (label, strides, rows, cols) = match axis {
+            Axis::X => ("z\\y", (1, nx * ny, nx), z, y),
+            Axis::Y => ("z\\x", (nx, nx * ny, 1), z, x),
+            Axis::Z => ("y\\x", (nx * ny, nx, 1), y, x),
+        };
+        let cell = |row: usize, col: usize| plane * strides.0 + row * strides.1 + col * strides.2;
+        let position = |(origin, spacing, _): (f64, f64, usize), c: usize| origin + c as f64 * spacing;
+
+        write!(writer, "{}", label)?;
+        for col in 0..cols.2 {
+            write!(writer, ",{}", position(cols, col))?;
+        }
+        writeln!(writer)?;
+        for row in 0..rows.2 {
+            write!(writer, "{}", position(rows, row))?;
+            for col in 0..cols.2 {
+                write!(writer, ",{}", self.field[cell(row, col)].density())?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+
+    /// Write the field as a legacy ASCII VTK structured-points dataset
+    ///
+    /// Opens directly in ParaView or VisIt: the grid carries origin and
+    /// spacing from `bounds`, with the density as the point scalar `density`.
+    /// A 2D field is written as a single z = 0 layer.
+    pub fn export_vtk<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let (nx, ny, nz) = self.shape;
+        let (dx, dy, dz) = self.spacing();
+        let ((x0, _), (y0, _), (z0, _)) = self.extents;
+        // A single layer still needs a nonzero spacing
+        let (origin_z, dz) = if self.planar { (0.0, dx) } else { (z0, dz) };
+
+        writeln!(writer, "# vtk DataFile Version 3.0")?;
+        writeln!(writer, "IIRT information density, step {} t = {}", self.step, self.time)?;
+        writeln!(writer, "ASCII")?;
+        writeln!(writer, "DATASET STRUCTURED_POINTS")?;
+        writeln!(writer, "DIMENSIONS {} {} {}", nx, ny, nz)?;
+        writeln!(writer, "ORIGIN {} {} {}", x0, y0, origin_z)?;
+        writeln!(writer, "SPACING {} {} {}", dx, dy, dz)?;
+        writeln!(writer, "POINT_DATA {}", self.field.len())?;
+        writeln!(writer, "SCALARS density double 1")?;
+        writeln!(writer, "LOOKUP_TABLE default")?;
+        // VTK point order is x-fastest, as is the field
+        for info in &self.field {
+            writeln!(writer, "{}", info.density())?;
+        }
+        writer.flush()
+    }
+
+    /// Write the densities as a NumPy `.npy` array (format 1.0)
+    ///
+    /// C-order little-endian float64 of shape (nz, ny, nx) - (ny, nx) for a
+    /// 2D field - so `numpy.load` yields `array[k, j, i]`: z, y, x. Grid
+    /// parameters are not part of the format; see `from_npy`.
+    pub fn export_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let (nx, ny, nz) = self.shape;
+        let shape = if self.planar { format!("({}, {})", ny, nx) } else { format!("({}, {}, {})", nz, ny, nx) };
+        let mut header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}", shape);
+        // Pad so the data starts 64-byte aligned, ending in a newline
+        let unpadded = NPY_MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+        header.push('\n');
+
+        writer.write_all(NPY_MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for info in &self.field {
+            writer.write_all(&info.density().to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    /// Build a field from a `.npy` array written by `export_npy` or NumPy
+    ///
+    /// Accepts C-order `<f8` arrays of shape (N, N, N), or (N, N) for a 2D
+    /// field, with N up to 4096, and uses the current cosmic age. Fails with
+    /// `InvalidData` on any other header and on parameters
+    /// `RealityBuilder::build` rejects, and with `UnexpectedEof` when the
+    /// data is shorter than the shape.
+    pub fn from_npy<R: Read>(reader: R, bounds: (f64, f64), diffusion: f64, dt: f64) -> io::Result<Reality> {
+        let mut reader = BufReader::new(reader);
+        let mut preamble = [0u8; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != NPY_MAGIC {
+            return Err(invalid("not a .npy file (bad magic)"));
+        }
+        let header_len = match preamble[6] {
+            1 => {
+                let mut bytes = [0u8; 2];
+                reader.read_exact(&mut bytes)?;
+                u16::from_le_bytes(bytes) as usize
+            }
+            2 | 3 => {
+                let mut bytes = [0u8; 4];
+                reader.read_exact(&mut bytes)?;
+                u32::from_le_bytes(bytes) as usize
+            }
+            other => return Err(invalid(&format!("unsupported .npy version {}", other))),
+        };
+        if header_len > NPY_MAX_HEADER {
+            return Err(invalid(&format!("implausible .npy header length {}", header_len)));
+        }
+        let mut header = vec![0u8; header_len];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8(header).map_err(|_| invalid(".npy header is not text"))?;
+
+        let (descr, fortran_order, dims) = parse_npy_header(&header)?;
+        if descr != "<f8" || fortran_order {
+            return Err(invalid("expected a C-order little-endian float64 array"));
+        }
+        let planar = match dims.as_slice() {
+            [a, b, c] if a == b && b == c => false,
+            [a, b] if a == b => true,
+            _ => return Err(invalid(&format!("expected shape (N, N, N) or (N, N), found {:?}", dims))),
+        };
+        if !(1..=MAX_RESOLUTION).contains(&dims[0]) {
+            return Err(invalid(&format!("implausible resolution {}", dims[0])));
+        }
+        let bytes = dims.iter()
+            .try_fold(8usize, |bytes, &dim| bytes.checked_mul(dim))
+            .ok_or_else(|| invalid(&format!("shape {:?} overflows", dims)))?;
+
+        // Read the data before building, so a header promising more cells
+        // than the stream holds fails without allocating the full grid
+        let mut data = Vec::new();
+        reader.take(bytes as u64).read_to_end(&mut data)?;
+        if data.len() < bytes {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated .npy data"));
+        }
+        let mut reality = RealityBuilder::new()
+            .resolution(dims[0])
+            .bounds(bounds)
+            .diffusion(diffusion)
+            .dt(dt)
+            .planar(planar)
+            .build()
+            .map_err(|error| invalid(&error.to_string()))?;
+        for (cell, value) in reality.field.iter_mut().zip(data.chunks_exact(8)) {
+            *cell = Information(f64::from_le_bytes(value.try_into().expect("chunks of 8 bytes")));
+        }
+        reality.recount();
+        Ok(reality)
+    }
+
+    /// Write the recorded `HistoryFrame`s as a JSON array of objects
+    ///
+    /// One object per frame, oldest first, keyed by the frame's field names
+    /// (`step`, `time`, `total_information`, `conscious_count`,
+    /// `max_consciousness`). Non-finite values become `null`. Writes `[]`
+    /// when history is off or nothing has been recorded yet.
+    #[cfg(feature = "serde")]
+    pub fn history_to_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        serde_json::to_writer(&mut writer, self.history())?;
+        writeln!(writer)?;
+        writer.flush()
+    }
+
+    /// Save a reproducible experiment bundle into directory `path`
+    ///
+    /// Writes the field state, the config, every probe series and - if
+    /// recording - the op log, as separate files in one directory that can be
+    /// handed to a collaborator as-is. The directory is created if missing.
+    /// While recording, the config is the one from `start_recording`, so
+    /// `Reality::replay` of the bundle's config and ops redoes the run.
+    pub fn save_bundle<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let dir = path.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let mut field = BufWriter::new(File::create(dir.join(BUNDLE_FIELD))?);
+        write_state(self, &mut field)?;
+        field.flush()?;
+
+        let mut config = BufWriter::new(File::create(dir.join(BUNDLE_CONFIG))?);
+        let start = self.recording_config().cloned().unwrap_or_else(|| self.config());
+        write_config(&start, &mut config)?;
+        config.flush()?;
+
+        let mut probes = BufWriter::new(File::create(dir.join(BUNDLE_PROBES))?);
+        for probe in &self.probes {
+            let (x, y, z) = probe.position;
+            writeln!(probes, "probe {} {} {}", x, y, z)?;
+            for (time, density) in &probe.samples {
+                writeln!(probes, "{} {}", time, density)?;
+            }
+        }
+        probes.flush()?;
+
+        let ops_path = dir.join(BUNDLE_OPS);
+        match &self.recording {
+            Some((_, ops)) => {
+                let mut file = BufWriter::new(File::create(ops_path)?);
+                for op in ops {
+                    match op {
+                        Op::AddInformation { position: (x, y, z), amplitude } => {
+                            writeln!(file, "add {} {} {} {}", x, y, z, amplitude)?
+                        }
+                        Op::Evolve => writeln!(file, "evolve")?,
+                        Op::EvolveRk4 => writeln!(file, "evolve_rk4")?,
+                        Op::EvolveBackward => writeln!(file, "evolve_backward")?,
+                        Op::ResetToVacuum => writeln!(file, "reset")?,
+                        Op::EvolveImplicit => writeln!(file, "evolve_implicit")?,
+                        Op::SetDt(dt) => writeln!(file, "dt {}", dt)?,
+                        Op::SetDiffusion(diffusion) => writeln!(file, "diffusion {}", diffusion)?,
+                        Op::SetDiffusionXyz(x, y, z) => writeln!(file, "diffusion_xyz {} {} {}", x, y, z)?,
+                        Op::AddNoise { amplitude, seed } => writeln!(file, "noise {} {}", amplitude, seed)?,
+                        Op::RemoveInformation { position: (x, y, z), amount } => {
+                            writeln!(file, "remove {} {} {} {}", x, y, z, amount)?
+                        }
+                        Op::SetDensity { position: (x, y, z), value } => {
+                            writeln!(file, "set {} {} {} {}", x, y, z, value)?
+                        }
+                        Op::AddGaussian { center: (x, y, z), amplitude, sigma } => {
+                            writeln!(file, "gaussian {} {} {} {} {}", x, y, z, amplitude, sigma)?
+                        }
+                        Op::AddSphericalShell { center: (x, y, z), radius, amplitude, thickness } => {
+                            writeln!(file, "shell {} {} {} {} {} {}", x, y, z, radius, amplitude, thickness)?
+                        }
+                        Op::LinearGradient { axis, low, high, region: (start, end) } => {
+                            let axis = match axis {
+                                Axis::X => "x",
+                                Axis::Y => "y",
+                                Axis::Z => "z",
+                            };
+                            writeln!(file, "linear_gradient {} {} {} {} {}", axis, low, high, start, end)?
+                        }
+                        Op::SetMaxInformation(i_max) => writeln!(file, "max_information {}", i_max)?,
+                        Op::AdvanceCosmicTime(delta_gyr) => writeln!(file, "advance_cosmic_time {}", delta_gyr)?,
+                        Op::PinRegion { region: ((x0, y0, z0), (x1, y1, z1)), density } => {
+                            writeln!(file, "pin {} {} {} {} {} {} {}", x0, y0, z0, x1, y1, z1, density)?
+                        }
+                        Op::UnpinRegion(((x0, y0, z0), (x1, y1, z1))) => {
+                            writeln!(file, "unpin {} {} {} {} {} {}", x0, y0, z0, x1, y1, z1)?
+                        }
+                    }
+                }
+                file.flush()?;
+            }
+            None if ops_path.exists() => fs::remove_file(ops_path)?,
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Load a bundle written by `save_bundle`
+    ///
+    /// The returned Reality carries the probes and, if one was saved, an
+    /// active op log. Probe histories and config are also returned directly.
+    pub fn load_bundle<P: AsRef<Path>>(path: P) -> io::Result<(Reality, Vec<ProbeHistory>, RealityConfig)> {
+        let dir = path.as_ref();
+
+        let mut reality = read_state_file(&dir.join(BUNDLE_FIELD))?;
+        let config = read_config(BufReader::new(File::open(dir.join(BUNDLE_CONFIG))?))?;
+
+        let mut probes: Vec<ProbeHistory> = Vec::new();
+        for line in BufReader::new(File::open(dir.join(BUNDLE_PROBES))?).lines() {
+            let line = line?;
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("probe") => {
+                    let [x, y, z] = parse_floats(words)?;
+                    probes.push(ProbeHistory { position: (x, y, z), samples: Vec::new() });
+                }
+                Some(first) => {
+                    let time = parse_float(first)?;
+                    let [density] = parse_floats(words)?;
+                    probes.last_mut()
+                        .ok_or_else(|| invalid("probe sample before probe header"))?
+                        .samples.push((time, density));
+                }
+                None => {}
+            }
+        }
+
+        let ops_path = dir.join(BUNDLE_OPS);
+        if ops_path.exists() {
+            let mut ops = Vec::new();
+            for line in BufReader::new(File::open(ops_path)?).lines() {
+                let line = line?;
+                let mut words = line.split_whitespace();
+                let op = match words.next() {
+                    Some("add") => {
+                        let [x, y, z, amplitude] = parse_floats(words)?;
+                        Op::AddInformation { position: (x, y, z), amplitude }
+                    }
+                    Some("remove") => {
+                        let [x, y, z, amount] = parse_floats(words)?;
+                        Op::RemoveInformation { position: (x, y, z), amount }
+                    }
+                    Some("set") => {
+                        let [x, y, z, value] = parse_floats(words)?;
+                        Op::SetDensity { position: (x, y, z), value }
+                    }
+                    Some("evolve") => Op::Evolve,
+                    Some("evolve_rk4") => Op::EvolveRk4,
+                    Some("evolve_backward") => Op::EvolveBackward,
+                    Some("reset") => Op::ResetToVacuum,
+                    Some("evolve_implicit") => Op::EvolveImplicit,
+                    Some("dt") => Op::SetDt(parse_floats::<1>(words)?[0]),
+                    Some("diffusion") => Op::SetDiffusion(parse_floats::<1>(words)?[0]),
+                    Some("diffusion_xyz") => {
+                        let [x, y, z] = parse_floats(words)?;
+                        Op::SetDiffusionXyz(x, y, z)
+                    }
+                    Some("gaussian") => {
+                        let [x, y, z, amplitude, sigma] = parse_floats(words)?;
+                        Op::AddGaussian { center: (x, y, z), amplitude, sigma }
+                    }
+                    Some("shell") => {
+                        let [x, y, z, radius, amplitude, thickness] = parse_floats(words)?;
+                        Op::AddSphericalShell { center: (x, y, z), radius, amplitude, thickness }
+                    }
+                    Some("linear_gradient") => {
+                        let axis = match words.next() {
+                            Some("x") => Axis::X,
+                            Some("y") => Axis::Y,
+                            Some("z") => Axis::Z,
+                            _ => return Err(invalid("linear_gradient op needs an axis")),
+                        };
+                        let [low, high, start, end] = parse_floats(words)?;
+                        Op::LinearGradient { axis, low, high, region: (start, end) }
+                    }
+                    Some("max_information") => Op::SetMaxInformation(parse_floats::<1>(words)?[0]),
+                    Some("advance_cosmic_time") => Op::AdvanceCosmicTime(parse_floats::<1>(words)?[0]),
+                    Some("pin") => {
+                        let [x0, y0, z0, x1, y1, z1, density] = parse_floats(words)?;
+                        Op::PinRegion { region: ((x0, y0, z0), (x1, y1, z1)), density }
+                    }
+                    Some("unpin") => {
+                        let [x0, y0, z0, x1, y1, z1] = parse_floats(words)?;
+                        Op::UnpinRegion(((x0, y0, z0), (x1, y1, z1)))
+                    }
+                    Some("noise") => {
+                        let amplitude = parse_float(words.next().unwrap_or(""))?;
+                        let seed = words.next().and_then(|word| word.parse().ok())
+                            .ok_or_else(|| invalid("noise op needs an amplitude and a seed"))?;
+                        Op::AddNoise { amplitude, seed }
+                    }
+                    Some(other) => return Err(invalid(&format!("unknown op '{}'", other))),
+                    None => continue,
+                };
+                ops.push(op);
+            }
+            reality.recording = Some((config.clone(), ops));
+        }
+
+        reality.probes = probes.clone();
+        Ok((reality, probes, config))
+    }
+}
+
+/// Write the binary field state
+pub(crate) fn write_state<W: Write>(reality: &Reality, writer: &mut W) -> io::Result<()> {
+    writer.write_all(FIELD_MAGIC)?;
+    writer.write_all(&[FIELD_FORMAT_VERSION, if reality.planar { 2 } else { 3 }])?;
+    let (nx, ny, nz) = reality.shape;
+    for count in [nx, ny, nz] {
+        writer.write_all(&(count as u64).to_le_bytes())?;
+    }
+    let ((x0, x1), (y0, y1), (z0, z1)) = reality.extents;
+    for value in [x0, x1, y0, y1, z0, z1, reality.diffusion, reality.dt, reality.cosmic_age, reality.time] {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    writer.write_all(&reality.step.to_le_bytes())?;
+    writer.write_all(&reality.max_information().to_le_bytes())?;
+    writer.write_all(&[reality.diffusion_xyz.is_some() as u8])?;
+    let (d_x, d_y, d_z) = reality.diffusion_xyz.unwrap_or((reality.diffusion, reality.diffusion, reality.diffusion));
+    for value in [d_x, d_y, d_z] {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    writer.write_all(&[reality.clamping as u8])?;
+    writer.write_all(&(reality.pins.len() as u64).to_le_bytes())?;
+    for info in &reality.field {
+        writer.write_all(&info.density().to_le_bytes())?;
+    }
+    for (idx, info) in &reality.pins {
+        writer.write_all(&(*idx as u64).to_le_bytes())?;
+        writer.write_all(&info.density().to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Read the binary field state from a file, see `read_state`
+fn read_state_file(path: &Path) -> io::Result<Reality> {
+    let file = File::open(path)?;
+    let len = file.metadata()?.len();
+    read_state(&mut BufReader::new(file), len)
+}
+
+/// Read the binary field state, rejecting unknown magic or versions
+///
+/// `len` is the number of bytes left in `reader`. A header promising more
+/// cells than that fails with `UnexpectedEof` before the grid is allocated.
+pub(crate) fn read_state<R: Read>(reader: &mut R, len: u64) -> io::Result<Reality> {
+    let reader = &mut reader.take(len);
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != FIELD_MAGIC {
+        return Err(invalid("not an IIRT field (bad magic header)"));
+    }
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version)?;
+    let planar = match version[0] {
+        1 => false,
+        2..=FIELD_FORMAT_VERSION => {
+            let mut dimensions = [0u8; 1];
+            reader.read_exact(&mut dimensions)?;
+            match dimensions[0] {
+                2 => true,
+                3 => false,
+                other => return Err(invalid(&format!("unsupported field dimension {}", other))),
+            }
+        }
+        other => return Err(invalid(&format!("unsupported IIRT field format version {}", other))),
+    };
+
+    let mut read_count = || -> io::Result<usize> {
+        let count = read_u64(reader)? as usize;
+        if (1..=MAX_RESOLUTION).contains(&count) { Ok(count) } else { Err(invalid(&format!("implausible resolution {}", count))) }
+    };
+    let (shape, extents) = if version[0] >= 3 {
+        let shape = (read_count()?, read_count()?, read_count()?);
+        let mut axis = || -> io::Result<(f64, f64)> { Ok((read_f64(reader)?, read_f64(reader)?)) };
+        (shape, (axis()?, axis()?, axis()?))
+    } else {
+        let n = read_count()?;
+        let bounds = (read_f64(reader)?, read_f64(reader)?);
+        ((n, n, n), (bounds, bounds, bounds))
+    };
+    let diffusion = read_f64(reader)?;
+    let dt = read_f64(reader)?;
+    let cosmic_age = read_f64(reader)?;
+    let time = read_f64(reader)?;
+    let step = read_u64(reader)?;
+    let max_information = if version[0] >= 4 { read_f64(reader)? } else { MAX_INFORMATION };
+    if !(max_information > INTEGRATION_THRESHOLD && max_information.is_finite()) {
+        return Err(invalid(&format!("implausible max information {}", max_information)));
+    }
+    let diffusion_xyz = if version[0] >= 5 {
+        let mut anisotropic = [0u8; 1];
+        reader.read_exact(&mut anisotropic)?;
+        let axes = (read_f64(reader)?, read_f64(reader)?, read_f64(reader)?);
+        if [axes.0, axes.1, axes.2].iter().any(|d| !d.is_finite() || *d < 0.0) {
+            return Err(invalid(&format!("implausible per-axis diffusion {:?}", axes)));
+        }
+        match anisotropic[0] {
+            0 => None,
+            1 => Some(axes),
+            other => return Err(invalid(&format!("bad anisotropic flag {}", other))),
+        }
+    } else {
+        None
+    };
+    let (clamping, pin_count) = if version[0] >= 6 {
+        let mut clamping = [0u8; 1];
+        reader.read_exact(&mut clamping)?;
+        let clamping = match clamping[0] {
+            0 => false,
+            1 => true,
+            other => return Err(invalid(&format!("bad clamping flag {}", other))),
+        };
+        (clamping, read_u64(reader)?)
+    } else {
+        (false, 0)
+    };
+
+    let cells = shape.0 * shape.1 * shape.2;
+    let bytes = (cells as u64).checked_mul(8)
+        .zip(pin_count.checked_mul(16))
+        .and_then(|(densities, pins)| densities.checked_add(pins));
+    if bytes.is_none_or(|bytes| bytes > reader.limit()) {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "field data shorter than its header's shape"));
+    }
+    let mut reality = RealityBuilder::new()
+        .shape(shape)
+        .extents(extents)
+        .diffusion(diffusion)
+        .dt(dt)
+        .cosmic_age(cosmic_age)
+        .planar(planar)
+        .skip_stability_check()
+        .build()
+        .map_err(|error| invalid(&error.to_string()))?;
+    reality.set_max_information(max_information);
+    if let Some((d_x, d_y, d_z)) = diffusion_xyz {
+        reality.set_diffusion_xyz(d_x, d_y, d_z);
+    }
+    reality.set_clamping(clamping);
+    for cell in reality.field.iter_mut() {
+        *cell = Information(read_f64(reader)?);
+    }
+    for _ in 0..pin_count {
+        let idx = read_u64(reader)? as usize;
+        let density = read_f64(reader)?;
+        if idx >= cells || reality.pins.last().is_some_and(|&(last, _)| last >= idx) {
+            return Err(invalid(&format!("bad pinned cell index {}", idx)));
+        }
+        if !(0.0..=max_information).contains(&density) {
+            return Err(invalid(&format!("bad pinned density {}", density)));
+        }
+        reality.pins.push((idx, Information(density)));
+    }
+    reality.time = time;
+    reality.step = step;
+    reality.recount();
+    Ok(reality)
+}
+
+fn write_config<W: Write>(config: &RealityConfig, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "resolution = {}", config.resolution)?;
+    writeln!(writer, "bounds = {} {}", config.bounds.0, config.bounds.1)?;
+    writeln!(writer, "diffusion = {}", config.diffusion)?;
+    writeln!(writer, "dt = {}", config.dt)?;
+    writeln!(writer, "cosmic_age = {}", config.cosmic_age)?;
+    writeln!(writer, "noise = {}", config.noise)?;
+    if config.planar {
+        writeln!(writer, "planar = true")?;
+    }
+    if let Some((nx, ny, nz)) = config.shape {
+        writeln!(writer, "shape = {} {} {}", nx, ny, nz)?;
+    }
+    if let Some(((x0, x1), (y0, y1), (z0, z1))) = config.extents {
+        writeln!(writer, "extents = {} {} {} {} {} {}", x0, x1, y0, y1, z0, z1)?;
+    }
+    for ((x, y, z), amplitude) in &config.deposits {
+        writeln!(writer, "deposit = {} {} {} {}", x, y, z, amplitude)?;
+    }
+    Ok(())
+}
+
+fn read_config<R: BufRead>(reader: R) -> io::Result<RealityConfig> {
+    let mut config = RealityConfig::default();
+    for line in reader.lines() {
+        let line = line?;
+        let Some((key, value)) = line.split_once('=') else { continue };
+        let words = value.split_whitespace();
+        match key.trim() {
+            "resolution" => {
+                config.resolution = value.trim().parse()
+                    .map_err(|_| invalid(&format!("bad resolution '{}'", value.trim())))?
+            }
+            "bounds" => {
+                let [min, max] = parse_floats(words)?;
+                config.bounds = (min, max);
+            }
+            "diffusion" => config.diffusion = parse_floats::<1>(words)?[0],
+            "dt" => config.dt = parse_floats::<1>(words)?[0],
+            "cosmic_age" => config.cosmic_age = parse_floats::<1>(words)?[0],
+            "noise" => config.noise = parse_floats::<1>(words)?[0],
+            "planar" => {
+                config.planar = value.trim().parse()
+                    .map_err(|_| invalid(&format!("bad planar flag '{}'", value.trim())))?
+            }
+            "shape" => {
+                let counts = words
+                    .map(|word| word.parse().map_err(|_| invalid(&format!("bad shape '{}'", value.trim()))))
+                    .collect::<io::Result<Vec<usize>>>()?;
+                let [nx, ny, nz] = counts[..] else {
+                    return Err(invalid(&format!("bad shape '{}'", value.trim())));
+                };
+                config.shape = Some((nx, ny, nz));
+            }
+            "extents" => {
+                let [x0, x1, y0, y1, z0, z1] = parse_floats(words)?;
+                config.extents = Some(((x0, x1), (y0, y1), (z0, z1)));
+            }
+            "deposit" => {
+                let [x, y, z, amplitude] = parse_floats(words)?;
+                config.deposits.push(((x, y, z), amplitude));
+            }
+            other => return Err(invalid(&format!("unknown config key '{}'", other))),
+        }
+    }
+    Ok(config)
+}
+
+fn parse_float(word: &str) -> io::Result<f64> {
+    word.parse().map_err(|_| invalid(&format!("bad number '{}'", word)))
+}
+
+fn parse_floats<'a, const N: usize>(words: impl Iterator<Item = &'a str>) -> io::Result<[f64; N]> {
+    let values = words.map(parse_float).collect::<io::Result<Vec<f64>>>()?;
+    values.try_into().map_err(|values: Vec<f64>| {
+        invalid(&format!("expected {} numbers, found {}", N, values.len()))
+    })
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_f64<R: Read>(reader: &mut R) -> io::Result<f64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+/// The `descr`, `fortran_order` and `shape` of a `.npy` header
+///
+/// The header is a Python dict literal holding exactly those three keys:
+/// `descr` a quoted string, `fortran_order` `True` or `False`, `shape` a
+/// tuple of integers. Anything else is `InvalidData`.
+fn parse_npy_header(header: &str) -> io::Result<(String, bool, Vec<usize>)> {
+    let malformed = || invalid(&format!("malformed .npy header {:?}", header.trim_end()));
+    let mut rest = header.trim_end()
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(malformed)?;
+    let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let (key, after) = python_string(rest).ok_or_else(malformed)?;
+        rest = after.trim_start().strip_prefix(':').ok_or_else(malformed)?.trim_start();
+        rest = match key {
+            "descr" if descr.is_none() => {
+                let (value, after) = python_string(rest).ok_or_else(malformed)?;
+                descr = Some(value.to_string());
+                after
+            }
+            "fortran_order" if fortran_order.is_none() => {
+                let (value, after) = if let Some(after) = rest.strip_prefix("True") {
+                    (true, after)
+                } else {
+                    (false, rest.strip_prefix("False").ok_or_else(malformed)?)
+                };
+                fortran_order = Some(value);
+                after
+            }
+            "shape" if shape.is_none() => {
+                let (tuple, after) = rest.strip_prefix('(')
+                    .and_then(|rest| rest.split_once(')'))
+                    .ok_or_else(malformed)?;
+                let mut items: Vec<&str> = tuple.split(',').map(str::trim).collect();
+                // A trailing comma, required for a 1-tuple, leaves one empty
+                // item; `()` is the 0-d shape
+                if items.last() == Some(&"") {
+                    items.pop();
+                }
+                let dims = items.iter()
+                    .map(|item| item.parse::<usize>().map_err(|_| invalid(&format!("bad .npy dimension '{}'", item))))
+                    .collect::<io::Result<Vec<usize>>>()?;
+                shape = Some(dims);
+                after
+            }
+            _ => return Err(invalid(&format!("unexpected or repeated .npy header key '{}'", key))),
+        };
+        rest = rest.trim_start();
+        match rest.strip_prefix(',') {
+            Some(after) => rest = after,
+            None if rest.is_empty() => break,
+            None => return Err(malformed()),
+        }
+    }
+    match (descr, fortran_order, shape) {
+        (Some(descr), Some(fortran_order), Some(shape)) => Ok((descr, fortran_order, shape)),
+        _ => Err(invalid("a .npy header needs descr, fortran_order and shape")),
+    }
+}
+
+/// A leading single- or double-quoted Python string and the text after it
+fn python_string(text: &str) -> Option<(&str, &str)> {
+    let quote = text.chars().next().filter(|&c| c == '\'' || c == '"')?;
+    text[1..].split_once(quote)
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_STABILITY_NUMBER;
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let config = RealityConfig {
+            resolution: 10,
+            bounds: (-1.0, 1.0),
+            dt: 0.002,
+            cosmic_age: 3.3,
+            ..RealityConfig::default()
+        };
+        let mut reality = config.build();
+        reality.add_probe((0.0, 0.0, 0.0));
+        reality.add_probe((0.5, 0.0, 0.0));
+        reality.start_recording();
+        reality.add_information((0.0, 0.0, 0.0), 1.7);
+        reality.set_diffusion(0.7);
+        reality.add_noise(0.1, u64::MAX - 3);
+        reality.add_gaussian((0.2, -0.1, 0.0), 0.8, 0.3);
+        reality.remove_information((0.0, 0.0, 0.0), 0.4);
+        reality.set_density((-0.5, 0.5, 0.0), 0.25);
+        reality.add_linear_gradient(Axis::Y, 1.0, 0.5, (0.6, -0.4));
+        reality.add_spherical_shell((0.0, 0.1, 0.0), 0.6, 0.9, 0.15);
+        reality.advance_cosmic_time(0.25);
+        reality.pin_region((-0.2, -0.2, -0.2), (0.2, 0.2, 0.2), 2.5);
+        for _ in 0..4 {
+            reality.evolve();
+        }
+        reality.unpin_region((-0.2, -0.2, 0.0), (0.2, 0.2, 0.2));
+        reality.evolve_implicit();
+
+        let dir = std::env::temp_dir().join(format!("iirt_bundle_{}", std::process::id()));
+        reality.save_bundle(&dir).unwrap();
+        let (loaded, probes, loaded_config) = Reality::load_bundle(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.difference(&reality), 0.0);
+        assert_eq!(loaded.step(), reality.step());
+        assert_eq!(loaded.time(), reality.time());
+        assert_eq!(loaded.pins, reality.pins);
+        assert_eq!(probes.len(), 2);
+        assert_eq!(probes, reality.probe_histories());
+        assert_eq!(probes[0].samples.len(), 5);
+        assert_eq!(loaded_config, config);
+        assert_eq!(loaded.recorded_ops(), reality.recorded_ops());
+        assert_eq!(loaded.recording_config(), Some(&config));
+        // The bundle's config is the start of the recorded run
+        let replayed = Reality::replay(&loaded_config, loaded.recorded_ops());
+        assert_eq!(replayed.difference(&loaded), 0.0);
+        assert_eq!(replayed.config(), loaded.config());
+    }
+
+    #[test]
+    fn test_checkpoint_branches_in_memory() {
+        let mut reality = Reality::new_2d(12, (-1.0, 1.0), 0.8, 0.01);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.evolve();
+        
+        let bytes = reality.checkpoint();
+        let mut branch = Reality::from_checkpoint(&bytes).unwrap();
+        assert_eq!(branch.difference(&reality), 0.0);
+        assert_eq!((branch.step(), branch.time()), (reality.step(), reality.time()));
+        
+        reality.evolve();
+        branch.evolve();
+        assert_eq!(branch.difference(&reality), 0.0);
+        
+        let kind = |bytes: &[u8]| Reality::from_checkpoint(bytes).err().unwrap().kind();
+        assert_eq!(kind(&bytes[..bytes.len() - 1]), io::ErrorKind::UnexpectedEof);
+        assert_eq!(kind(&[bytes.as_slice(), &[0]].concat()), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_max_information() {
+        let mut reality = Reality::new(6, (-1.0, 1.0), 0.8, 0.01);
+        reality.set_max_information(30.0);
+        reality.set_density((0.2, 0.2, 0.2), 24.0);
+        reality.evolve();
+
+        let path = std::env::temp_dir().join(format!("iirt_max_information_{}.bin", std::process::id()));
+        reality.save(&path).unwrap();
+        let mut loaded = Reality::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let mut branch = Reality::from_checkpoint(&reality.checkpoint()).unwrap();
+        assert_eq!((loaded.max_information(), branch.max_information()), (30.0, 30.0));
+        assert_eq!(loaded.difference(&reality), 0.0);
+
+        // Cells above 16 keep evolving under the saved logistic term
+        reality.evolve();
+        loaded.evolve();
+        branch.evolve();
+        assert_eq!(loaded.difference(&reality), 0.0);
+        assert_eq!(branch.difference(&reality), 0.0);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_per_axis_diffusion() {
+        let mut reality = Reality::new(7, (-1.0, 1.0), 0.6, 0.01);
+        reality.set_diffusion_xyz(0.2, 0.6, 1.0);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.evolve();
+
+        let mut branch = Reality::from_checkpoint(&reality.checkpoint()).unwrap();
+        assert_eq!(branch.diffusion_xyz, Some((0.2, 0.6, 1.0)));
+        assert_eq!(branch.diffusion(), reality.diffusion());
+        for _ in 0..3 {
+            reality.evolve();
+            branch.evolve();
+        }
+        assert_eq!(branch.difference(&reality), 0.0);
+
+        reality.set_diffusion(0.6);
+        assert_eq!(Reality::from_checkpoint(&reality.checkpoint()).unwrap().diffusion_xyz, None);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_pins_and_clamping() {
+        let mut reality = Reality::new(8, (-1.0, 1.0), 0.8, 0.01);
+        reality.pin_region((-0.3, -0.3, -0.3), (0.3, 0.3, 0.3), 3.0);
+        reality.pin_region((0.7, 0.7, 0.7), (1.0, 1.0, 1.0), 0.0);
+        reality.set_clamping(true);
+        reality.evolve();
+
+        let mut branch = Reality::from_checkpoint(&reality.checkpoint()).unwrap();
+        assert_eq!(branch.pins, reality.pins);
+        assert!(branch.clamping);
+        for _ in 0..5 {
+            reality.evolve();
+            branch.evolve();
+        }
+        assert_eq!(branch.difference(&reality), 0.0);
+        assert_eq!(branch.information_at((0.0, 0.0, 0.0)).unwrap().density(), 3.0);
+
+        // Pins out of order or off the grid are rejected
+        let mut bytes = reality.checkpoint();
+        let pins = bytes.len() - 16 * reality.pinned_count();
+        bytes[pins..pins + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let error = Reality::from_checkpoint(&bytes).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_round_trip_with_dt_past_explicit_limit() {
+        let mut reality = Reality::new(8, (-1.0, 1.0), 2.0, 0.01);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.set_dt_unchecked(0.5);
+        assert!(reality.stability_number() > MAX_STABILITY_NUMBER);
+        reality.evolve_implicit();
+
+        let restored = Reality::from_checkpoint(&reality.checkpoint()).unwrap();
+        assert_eq!(restored.difference(&reality), 0.0);
+        assert_eq!(restored.dt(), 0.5);
+        assert_eq!(reality.config().build().dt(), 0.5);
+        // Fresh construction still rejects the unstable explicit scheme
+        assert!(RealityBuilder::new().resolution(8).diffusion(2.0).dt(0.5).build().is_err());
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let mut reality = Reality::new_at_cosmic_age(8, (-2.0, 2.0), 0.7, 0.004, 5.5);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.evolve();
+
+        let path = std::env::temp_dir().join(format!("iirt_field_{}.bin", std::process::id()));
+        reality.save(&path).unwrap();
+        let loaded = Reality::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.difference(&reality), 0.0);
+        assert_eq!(loaded.config(), reality.config());
+        assert_eq!(loaded.time(), reality.time());
+        assert_eq!(loaded.step(), reality.step());
+        // Recounted on load with the reduction `evolve` totals with
+        assert_eq!(loaded.total_information(), reality.total_information());
+    }
+
+    #[test]
+    fn test_planar_round_trip() {
+        let mut reality = Reality::new_2d(6, (-1.0, 1.0), 1.0, 0.02);
+        reality.add_information((0.2, 0.2, 0.0), 1.0);
+        reality.evolve();
+        let mut bytes = Vec::new();
+        write_state(&reality, &mut bytes).unwrap();
+        assert_eq!(bytes.len(), 4 + 2 + 3 * 8 + 12 * 8 + 1 + 3 * 8 + 1 + 8 + 8 * 36);
+
+        let loaded = read_state(&mut bytes.as_slice(), bytes.len() as u64).unwrap();
+        assert_eq!(loaded.grid_point_count(), 36);
+        assert_eq!(loaded.difference(&reality), 0.0);
+        assert_eq!(loaded.config(), reality.config());
+
+        // Versions 1 and 2 store one resolution and bounds pair; version 1
+        // carries no dimensions byte and is 3D
+        let mut bytes = FIELD_MAGIC.to_vec();
+        bytes.push(1);
+        bytes.extend_from_slice(&3u64.to_le_bytes());
+        for value in [0.0, 1.0, 1.0, 0.01, 0.0, 0.0] {
+            bytes.extend_from_slice(&f64::to_le_bytes(value));
+        }
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend(std::iter::repeat_n(0u8, 8 * 27));
+        assert_eq!(read_state(&mut bytes.as_slice(), bytes.len() as u64).unwrap().grid_point_count(), 27);
+        bytes[4] = 2;
+        bytes.insert(5, 3);
+        assert_eq!(read_state(&mut bytes.as_slice(), bytes.len() as u64).unwrap().bounds(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_reads_versions_before_per_axis_diffusion_and_pins() {
+        let mut reality = Reality::new(4, (0.0, 1.0), 0.5, 0.01);
+        reality.set_max_information(12.0);
+        reality.set_diffusion_xyz(0.2, 0.4, 0.6);
+        reality.add_information((0.5, 0.5, 0.5), 1.0);
+        let bytes = reality.checkpoint();
+
+        // Version 6 appended clamping and pin_count after diffusion_xyz;
+        // version 5 appended anisotropic and diffusion_xyz after max_information
+        let max_information_end = 4 + 2 + 3 * 8 + 10 * 8 + 8 + 8;
+        let densities = &bytes[max_information_end + 1 + 3 * 8 + 1 + 8..];
+        let mut v5 = bytes[..max_information_end + 1 + 3 * 8].to_vec();
+        v5[4] = 5;
+        v5.extend_from_slice(densities);
+        let loaded = Reality::from_checkpoint(&v5).unwrap();
+        assert_eq!(loaded.max_information(), 12.0);
+        assert_eq!(loaded.diffusion_xyz, Some((0.2, 0.4, 0.6)));
+        assert_eq!(loaded.difference(&reality), 0.0);
+
+        let mut v4 = bytes[..max_information_end].to_vec();
+        v4[4] = 4;
+        v4.extend_from_slice(densities);
+        let loaded = Reality::from_checkpoint(&v4).unwrap();
+        assert_eq!(loaded.max_information(), 12.0);
+        assert_eq!(loaded.diffusion_xyz, None);
+        assert_eq!(loaded.difference(&reality), 0.0);
+    }
+
+    #[test]
+    fn test_rect_round_trip() {
+        let extents = ((-2.0, 2.0), (-1.0, 1.0), (0.0, 0.5));
+        let mut reality = Reality::new_rect((9, 5, 3), extents, 0.1, 0.01);
+        reality.add_information((0.5, 0.0, 0.25), 1.0);
+        reality.evolve();
+
+        let loaded = Reality::from_checkpoint(&reality.checkpoint()).unwrap();
+        assert_eq!(loaded.shape(), (9, 5, 3));
+        assert_eq!(loaded.extents(), extents);
+        assert_eq!(loaded.difference(&reality), 0.0);
+
+        let mut text = Vec::new();
+        write_config(&reality.config(), &mut text).unwrap();
+        let config = read_config(text.as_slice()).unwrap();
+        assert_eq!(config, reality.config());
+        assert_eq!(config.build().shape(), (9, 5, 3));
+    }
+
+    #[test]
+    fn test_load_rejects_bad_header() {
+        let reality = Reality::new(4, (0.0, 1.0), 1.0, 0.01);
+        let mut bytes = Vec::new();
+        write_state(&reality, &mut bytes).unwrap();
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = FIELD_FORMAT_VERSION + 1;
+        let error = read_state(&mut wrong_version.as_slice(), wrong_version.len() as u64).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        bytes[0] = b'X';
+        let error = read_state(&mut bytes.as_slice(), bytes.len() as u64).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_truncated_state_fails_before_allocating() {
+        // A current header for a 4096³ grid with no data behind it
+        let mut bytes = FIELD_MAGIC.to_vec();
+        bytes.extend_from_slice(&[FIELD_FORMAT_VERSION, 3]);
+        for _ in 0..3 {
+            bytes.extend_from_slice(&4096u64.to_le_bytes());
+        }
+        for value in [0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.01, 0.0, 0.0] {
+            bytes.extend_from_slice(&f64::to_le_bytes(value));
+        }
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&MAX_INFORMATION.to_le_bytes());
+        bytes.extend(std::iter::repeat_n(0u8, 1 + 3 * 8 + 1 + 8 + 64));
+        let error = Reality::from_checkpoint(&bytes).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let path = std::env::temp_dir().join(format!("iirt_truncated_{}.bin", std::process::id()));
+        fs::write(&path, &bytes).unwrap();
+        let error = Reality::load(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_export_slice_csv() {
+        let mut reality = Reality::new_at_cosmic_age(5, (0.0, 2.0), 1.0, 0.01, 0.0);
+        reality.add_information((1.5, 0.5, 1.0), 3.0);
+
+        let mut csv = Vec::new();
+        reality.export_slice_csv(&mut csv, Axis::Z, 1.1).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[0], vec!["y\\x", "0", "0.5", "1", "1.5", "2"]);
+        // Row y = 0.5, column x = 1.5
+        assert_eq!(rows[2][0], "0.5");
+        let expected = reality.information_at((1.5, 0.5, 1.0)).unwrap().density();
+        assert_eq!(rows[2][4].parse::<f64>().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_export_vtk() {
+        let mut reality = Reality::new_at_cosmic_age(4, (-1.5, 1.5), 1.0, 0.01, 0.0);
+        reality.add_information((0.5, -0.5, 1.5), 2.0);
+
+        let mut vtk = Vec::new();
+        reality.export_vtk(&mut vtk).unwrap();
+        let vtk = String::from_utf8(vtk).unwrap();
+        let lines: Vec<&str> = vtk.lines().collect();
+
+        assert_eq!(lines[0], "# vtk DataFile Version 3.0");
+        assert_eq!(lines[4], "DIMENSIONS 4 4 4");
+        assert_eq!(lines[5], "ORIGIN -1.5 -1.5 -1.5");
+        assert_eq!(lines[6], "SPACING 1 1 1");
+        assert_eq!(lines[7], "POINT_DATA 64");
+        assert_eq!(lines.len(), 10 + 64);
+        // Point (i, j, k) = (2, 1, 3) in x-fastest order
+        let value: f64 = lines[10 + 3 * 16 + 4 + 2].parse().unwrap();
+        assert_eq!(value, reality.information_at((0.5, -0.5, 1.5)).unwrap().density());
+
+        let mut vtk = Vec::new();
+        Reality::new_2d(4, (0.0, 3.0), 1.0, 0.01).export_vtk(&mut vtk).unwrap();
+        assert!(String::from_utf8(vtk).unwrap().contains("DIMENSIONS 4 4 1\nORIGIN 0 0 0\n"));
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let mut reality = Reality::new(5, (-1.0, 1.0), 0.9, 0.01);
+        reality.add_information((0.5, -0.5, 1.0), 2.0);
+        reality.evolve();
+
+        let mut npy = Vec::new();
+        reality.export_npy(&mut npy).unwrap();
+        assert_eq!(&npy[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        let header = std::str::from_utf8(&npy[10..10 + header_len]).unwrap();
+        assert!(header.contains("'shape': (5, 5, 5)") && header.ends_with('\n'));
+        assert_eq!(npy.len(), 10 + header_len + 8 * 125);
+
+        let loaded = Reality::from_npy(npy.as_slice(), (-1.0, 1.0), 0.9, 0.01).unwrap();
+        assert_eq!(loaded.difference(&reality), 0.0);
+        assert_eq!(loaded.grid_point_count(), 125);
+
+        let mut npy = Vec::new();
+        Reality::new_2d(6, (0.0, 1.0), 1.0, 0.01).export_npy(&mut npy).unwrap();
+        let planar = Reality::from_npy(npy.as_slice(), (0.0, 1.0), 1.0, 0.01).unwrap();
+        assert_eq!(planar.grid_point_count(), 36);
+
+        // Big-endian data is rejected
+        let at = npy.iter().position(|&byte| byte == b'<').unwrap();
+        npy[at] = b'>';
+        let error = Reality::from_npy(npy.as_slice(), (0.0, 1.0), 1.0, 0.01).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_from_npy_rejects_bad_headers() {
+        let npy = |header: &str, data: usize| {
+            let mut bytes = NPY_MAGIC.to_vec();
+            bytes.extend_from_slice(&[1, 0]);
+            bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(header.as_bytes());
+            bytes.extend(std::iter::repeat_n(0u8, data));
+            bytes
+        };
+        let kind = |bytes: Vec<u8>| Reality::from_npy(bytes.as_slice(), (0.0, 1.0), 1.0, 0.01).err().map(|error| error.kind());
+
+        let good = "{'descr': '<f8', 'fortran_order': False, 'shape': (3, 3), }\n";
+        assert_eq!(kind(npy(good, 8 * 9)), None);
+        assert_eq!(kind(npy("{\"shape\": (3, 3), \"descr\": \"<f8\", \"fortran_order\": False}", 8 * 9)), None);
+        assert_eq!(kind(npy(good, 8 * 9 - 1)), Some(io::ErrorKind::UnexpectedEof));
+        // A huge shape fails on the cap, not by allocating it
+        let huge = "{'descr': '<f8', 'fortran_order': False, 'shape': (100000, 100000, 100000), }";
+        assert_eq!(kind(npy(huge, 0)), Some(io::ErrorKind::InvalidData));
+        let promised = "{'descr': '<f8', 'fortran_order': False, 'shape': (4096, 4096, 4096), }";
+        assert_eq!(kind(npy(promised, 64)), Some(io::ErrorKind::UnexpectedEof));
+        for header in [
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (3, 3), 'extra': 1}",
+            "{'descr': '<f8', 'descr': '<f8', 'fortran_order': False, 'shape': (3, 3)}",
+            "{'descr': '<f8', 'shape': (3, 3)}",
+            "{'descr': '<f8' 'fortran_order': False, 'shape': (3, 3)}",
+            "{'descr': '<f8x', 'fortran_order': False, 'shape': (3, 3)}",
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (3, 3)}",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (3, , 3)}",
+            "'descr': '<f8', 'fortran_order': False, 'shape': (3, 3)",
+        ] {
+            assert_eq!(kind(npy(header, 8 * 9)), Some(io::ErrorKind::InvalidData), "{}", header);
+        }
+
+        // Version 2 header lengths are checked before allocating
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend_from_slice(&[2, 0]);
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(kind(bytes), Some(io::ErrorKind::InvalidData));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_history_to_json() {
+        let mut reality = Reality::new(5, (-1.0, 1.0), 0.5, 0.01);
+        let mut json = Vec::new();
+        reality.history_to_json(&mut json).unwrap();
+        assert_eq!(json, b"[]\n");
+
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        reality.enable_history(2);
+        for _ in 0..4 {
+            reality.evolve();
+        }
+        let mut json = Vec::new();
+        reality.history_to_json(&mut json).unwrap();
+        let frames: Vec<serde_json::Value> = serde_json::from_slice(&json).unwrap();
+        assert_eq!(frames.len(), reality.history().len());
+
+        let frame = reality.history()[1];
+        assert_eq!(frames[1]["step"], frame.step);
+        assert_eq!(frames[1]["time"], frame.time);
+        assert_eq!(frames[1]["total_information"], frame.total_information);
+        assert_eq!(frames[1]["conscious_count"], frame.conscious_count);
+        assert_eq!(frames[1]["max_consciousness"], frame.max_consciousness);
+
+        assert_eq!(serde_json::to_string(&f64::NAN).unwrap(), "null");
+    }
+}
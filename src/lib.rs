@@ -34,12 +34,27 @@
 //! 
 //! Sawyer Kent, 2025
 
+#[cfg(feature = "complex")]
+pub mod complex;
 pub mod constants;
+pub mod cosmology;
+pub mod em;
+pub mod io;
+pub mod patterns;
 pub mod reality;
+pub mod scales;
+#[cfg(feature = "fft")]
+pub mod spectrum;
+pub mod sweep;
+mod rng;
+mod stencil;
 
 // Re-export main components
 pub use reality::*;
 pub use constants::*;
+#[cfg(feature = "complex")]
+pub use complex::{ComplexReality, QuantumReality};
+pub use sweep::sweep;
 
 /// Create reality field initialized to vacuum state
 pub fn vacuum_reality() -> Reality {
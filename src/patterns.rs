@@ -0,0 +1,83 @@
+//! Seed Catalog - Named Initial Patterns
+//!
+//! The motifs the experiments seed repeatedly, defined once.
+//! Each constructor returns a deposit list of (position, amplitude) pairs
+//! for `Reality::add_information_batch`.
+
+use std::f64::consts::PI;
+
+/// Information density of a single electron-cloud point: 1.5 bits
+///
+/// The hydrogen-formation electron density used across the atomic experiments.
+pub const ELECTRON_DENSITY: f64 = 1.5;
+
+/// Spacing between consecutive jet cells along the flow axis
+pub const JET_SPACING: f64 = 0.3;
+
+/// Minimal self-copying seed: a 1.5-bit core with two 0.8-bit arms along +x and +y
+pub fn replicator(center: (f64, f64, f64)) -> Vec<((f64, f64, f64), f64)> {
+    let (x, y, z) = center;
+    vec![
+        ((x, y, z), 1.5),
+        ((x + 0.2, y, z), 0.8),
+        ((x, y + 0.2, z), 0.8),
+    ]
+}
+
+/// Information jet streaming along +x from `start`
+///
+/// Core cells every `JET_SPACING` over `length`, flanked at ±0.1 in y by
+/// slightly weaker sheath cells (90% of `amplitude`) that set up shear.
+pub fn jet(start: (f64, f64, f64), length: f64, amplitude: f64) -> Vec<((f64, f64, f64), f64)> {
+    let (x0, y, z) = start;
+    let cells = (length / JET_SPACING).floor() as usize + 1;
+
+    (0..cells).flat_map(|i| {
+        let x = x0 + i as f64 * JET_SPACING;
+        [
+            ((x, y, z), amplitude),
+            ((x, y + 0.1, z), 0.9 * amplitude),
+            ((x, y - 0.1, z), 0.9 * amplitude),
+        ]
+    }).collect()
+}
+
+/// Electron shell: a ring of `ELECTRON_DENSITY` points at `radius` in the z-plane of `center`
+///
+/// Two points per electron, at least six, for even angular coverage.
+pub fn orbital_shell(center: (f64, f64, f64), radius: f64, electrons: usize) -> Vec<((f64, f64, f64), f64)> {
+    let (cx, cy, cz) = center;
+    let points = (electrons * 2).max(6);
+
+    (0..points).map(|i| {
+        let angle = i as f64 * 2.0 * PI / points as f64;
+        ((cx + radius * angle.cos(), cy + radius * angle.sin(), cz), ELECTRON_DENSITY)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replicator_matches_evolution_seed() {
+        // Same deposits as create_replication_seed in emergent_evolution.rs
+        let center = (1.0, -1.0, 0.5);
+        assert_eq!(replicator(center), vec![
+            (center, 1.5),
+            ((center.0 + 0.2, center.1, center.2), 0.8),
+            ((center.0, center.1 + 0.2, center.2), 0.8),
+        ]);
+    }
+
+    #[test]
+    fn test_orbital_shell_radius() {
+        let shell = orbital_shell((0.0, 0.0, 0.0), 0.8, 1);
+        assert_eq!(shell.len(), 6);
+        for ((x, y, z), amplitude) in shell {
+            assert!(((x * x + y * y).sqrt() - 0.8).abs() < 1e-12);
+            assert_eq!(z, 0.0);
+            assert_eq!(amplitude, ELECTRON_DENSITY);
+        }
+    }
+}
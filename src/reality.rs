@@ -6,10 +6,20 @@
 //! Threshold: ℐ_crit = 1/√2
 
 use crate::constants::*;
+use crate::rng::SplitMix64;
+use crate::stencil;
+use std::ops::ControlFlow;
+use std::sync::Arc;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Cells per partial sum in `scan_totals`; fixes the reduction tree
+const REDUCTION_CHUNK: usize = 4096;
+
+/// Totals, largest |Δℐ| and budget of one chunk of an Euler step
+type EulerChunk = ((f64, usize), f64, InfoBalance);
+
 /// Information density at a spatial point
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Information(pub f64);
@@ -28,14 +38,26 @@ impl Information {
         self.0 >= INTEGRATION_THRESHOLD 
     }
     
+    /// Distance from the threshold: ℐ - ℐ_crit (negative when subconscious)
+    pub fn consciousness_margin(&self) -> f64 {
+        self.0 - INTEGRATION_THRESHOLD
+    }
+    
+    /// Self-reference ratio: ℐ/ℐ_crit (≥ 1 exactly when conscious)
+    pub fn self_reference_ratio(&self) -> f64 {
+        self.0 / INTEGRATION_THRESHOLD
+    }
+    
     /// Uncertainty: ε(ℐ) = max(0.5/(1+ℐ), ε_min)
-    fn uncertainty(&self) -> f64 {
+    /// 
+    /// Exactly the ε that `evolve` applies in the -ε²ℐ decay term.
+    pub fn uncertainty(&self) -> f64 {
         (0.5 / (1.0 + self.0)).max(MIN_UNCERTAINTY)
     }
     
     /// Self-creation: ℐ(1-ℐ/ℐ_max)
-    fn self_creation(&self) -> f64 {
-        self.0 * (1.0 - self.0 / MAX_INFORMATION)
+    fn self_creation(&self, max_information: f64) -> f64 {
+        growth_rate(self.0, max_information)
     }
     
     /// Uncertainty decay: -ε²ℐ
@@ -45,44 +67,600 @@ impl Information {
     
     /// Total intrinsic rate: -ε²ℐ + ℐ(1-ℐ/ℐ_max)
     pub fn intrinsic_rate(&self) -> f64 {
-        self.self_creation() + self.uncertainty_decay()
+        self.self_creation(MAX_INFORMATION) + self.uncertainty_decay()
     }
 }
 
-/// 3D Information field implementing IIRT dynamics
-pub struct Reality {
+/// Logistic self-amplification ℐ(1-ℐ/ℐ_max) of the master equation
+/// 
+/// Positive on (0, ℐ_max), zero at both ends and negative above ℐ_max.
+pub fn growth_rate(density: f64, max_information: f64) -> f64 {
+    density * (1.0 - density / max_information)
+}
+
+/// Spatial axis of the field grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+/// Local growth/decay behaviour of a cell over a short run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellClass {
+    /// Density grew by more than 50%
+    SelfAmplifying,
+    /// Density grew by 10–50%
+    Growing,
+    /// Density changed by less than 10%
+    Stable,
+    /// Density fell by more than 10%
+    Decaying,
+}
+
+impl CellClass {
+    /// Classify by amplification ratio ℐ_final/ℐ_initial
+    pub fn from_amplification(ratio: f64) -> Self {
+        if ratio > 1.5 {
+            CellClass::SelfAmplifying
+        } else if ratio > 1.1 {
+            CellClass::Growing
+        } else if ratio < 0.9 {
+            CellClass::Decaying
+        } else {
+            CellClass::Stable
+        }
+    }
+}
+
+/// Diagnostics about a single evolution step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepReport {
+    /// Largest absolute per-cell density change |Δℐ| in the step
+    pub max_change: f64,
+    /// Position of the cell with the largest change
+    pub at: (f64, f64, f64),
+    /// Total information after the step
+    pub total_information: f64,
+}
+
+/// A failed step, returned by `Reality::evolve_checked`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EvolveError {
+    /// A cell went non-finite or above `DIVERGENCE_FACTOR`·ℐ_max
+    Diverged {
+        /// Position of the first offending cell
+        position: (f64, f64, f64),
+        /// Its density
+        density: f64,
+    },
+}
+
+impl std::fmt::Display for EvolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvolveError::Diverged { position: (x, y, z), density } => write!(
+                f, "field diverged: density {} at ({}, {}, {}); reduce dt or enable clamping",
+                density, x, y, z),
+        }
+    }
+}
+
+impl std::error::Error for EvolveError {}
+
+/// Information budget of the last Euler step, see `Reality::information_balance`
+/// 
+/// All terms are in bits summed over cells, like `total_information`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct InfoBalance {
+    /// Growth source dt·Σℐ(1-ℐ/ℐ_max) over the updated cells
+    pub source: f64,
+    /// Uncertainty sink dt·Σε²ℐ over the updated cells
+    pub sink: f64,
+    /// Net diffusive outflow into the fixed face cells
+    pub boundary_flux: f64,
+    /// Measured change in total information
+    pub change: f64,
+}
+
+impl InfoBalance {
+    /// change - (source - sink - boundary_flux): zero up to rounding unless
+    /// densities were clamped
+    pub fn residual(&self) -> f64 {
+        self.change - (self.source - self.sink - self.boundary_flux)
+    }
+}
+
+/// Density time series sampled at a fixed position after every step
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbeHistory {
+    pub position: (f64, f64, f64),
+    /// (time, density) pairs
+    pub samples: Vec<(f64, f64)>,
+}
+
+/// Whole-field statistics recorded by `Reality::enable_history`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HistoryFrame {
+    pub step: u64,
+    pub time: f64,
+    pub total_information: f64,
+    pub conscious_count: usize,
+    /// Largest density anywhere in the field (bits)
+    pub max_consciousness: f64,
+}
+
+/// A recorded mutation of a Reality, for deterministic replay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    /// `add_information(position, amplitude)`
+    AddInformation { position: (f64, f64, f64), amplitude: f64 },
+    /// One `evolve()` step
+    Evolve,
+    /// One `evolve_rk4()` step
+    EvolveRk4,
+    /// One `evolve_backward()` step
+    EvolveBackward,
+    /// `set_dt(dt)`
+    SetDt(f64),
+    /// `set_diffusion(diffusion)`
+    SetDiffusion(f64),
+    /// `set_diffusion_xyz(x, y, z)`
+    SetDiffusionXyz(f64, f64, f64),
+    /// `remove_information(position, amount)`
+    RemoveInformation { position: (f64, f64, f64), amount: f64 },
+    /// `set_density(position, value)`
+    SetDensity { position: (f64, f64, f64), value: f64 },
+    /// `add_gaussian(center, amplitude, sigma)`
+    AddGaussian { center: (f64, f64, f64), amplitude: f64, sigma: f64 },
+    /// `evolve_implicit()`
+    EvolveImplicit,
+    /// `reset_to_vacuum()`
+    ResetToVacuum,
+    /// `add_spherical_shell(center, radius, amplitude, thickness)`
+    AddSphericalShell { center: (f64, f64, f64), radius: f64, amplitude: f64, thickness: f64 },
+    /// `add_linear_gradient(axis, low, high, region)`
+    LinearGradient { axis: Axis, low: f64, high: f64, region: (f64, f64) },
+    /// `add_noise(amplitude, seed)`
+    AddNoise { amplitude: f64, seed: u64 },
+    /// `set_max_information(i_max)`
+    SetMaxInformation(f64),
+    /// `advance_cosmic_time(delta_gyr)`
+    AdvanceCosmicTime(f64),
+    /// `pin_region(min, max, density)`
+    PinRegion { region: Region, density: f64 },
+    /// `unpin_region(min, max)`
+    UnpinRegion(Region),
+}
+
+/// Axis-aligned box given by its (min, max) corners
+pub type Region = ((f64, f64, f64), (f64, f64, f64));
+
+/// Cell position and information current J there, see `Reality::current_field`
+pub type CurrentSample = ((f64, f64, f64), (f64, f64, f64));
+
+/// Per-axis (min, max) extents of a rectangular grid, see `Reality::new_rect`
+pub type Extents = ((f64, f64), (f64, f64), (f64, f64));
+
+/// Saved field state for branching experiments, see `Reality::snapshot`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealitySnapshot {
     field: Vec<Information>,
+    time: f64,
+    step: u64,
+    total_information: f64,
+    conscious_count: usize,
+}
+
+/// Reproducible description of a starting field
+/// 
+/// Grid parameters plus the initial deposits and per-cell noise amplitude.
+/// `build_seeded` turns the same config and seed into the same field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealityConfig {
+    pub resolution: usize,
+    pub bounds: (f64, f64),
+    pub diffusion: f64,
+    pub dt: f64,
+    pub cosmic_age: f64,
+    /// Information deposits applied after vacuum initialization
+    pub deposits: Vec<((f64, f64, f64), f64)>,
+    /// Amplitude of uniform per-cell noise in [-noise, noise]
+    pub noise: f64,
+    /// Two-dimensional N×N grid (see `Reality::new_2d`)
+    pub planar: bool,
+    /// Cells along (x, y, z) of a rectangular grid, overriding `resolution`
+    pub shape: Option<(usize, usize, usize)>,
+    /// Per-axis extents of a rectangular grid, overriding `bounds`
+    pub extents: Option<Extents>,
+}
+
+impl Default for RealityConfig {
+    fn default() -> Self {
+        Self {
+            resolution: DEFAULT_RESOLUTION,
+            bounds: DEFAULT_BOUNDS,
+            diffusion: DEFAULT_DIFFUSION,
+            dt: DEFAULT_DT,
+            cosmic_age: CURRENT_COSMIC_AGE_GYR,
+            deposits: Vec::new(),
+            noise: 0.0,
+            planar: false,
+            shape: None,
+            extents: None,
+        }
+    }
+}
+
+impl RealityConfig {
+    /// Build the field with noise seed 0
+    pub fn build(&self) -> Reality {
+        self.build_seeded(0)
+    }
+    
+    /// Build the field, drawing the noise from `seed`
+    /// 
+    /// Panics on invalid grid parameters or a deposit off the grid. A dt
+    /// past the explicit stability limit is accepted, since configs also
+    /// describe saved fields whose dt was raised for `evolve_implicit`.
+    pub fn build_seeded(&self, seed: u64) -> Reality {
+        let mut builder = RealityBuilder::new()
+            .skip_stability_check()
+            .resolution(self.resolution)
+            .bounds(self.bounds)
+            .diffusion(self.diffusion)
+            .dt(self.dt)
+            .cosmic_age(self.cosmic_age)
+            .planar(self.planar);
+        if let Some(shape) = self.shape {
+            builder = builder.shape(shape);
+        }
+        if let Some(extents) = self.extents {
+            builder = builder.extents(extents);
+        }
+        let mut reality = builder
+            .build()
+            .unwrap_or_else(|error| panic!("invalid Reality parameters: {}", error));
+        reality.add_information_batch(&self.deposits)
+            .unwrap_or_else(|error| panic!("invalid RealityConfig deposit: {}", error));
+        if self.noise != 0.0 {
+            reality.add_noise(self.noise, seed);
+        }
+        reality
+    }
+}
+
+/// Invalid grid parameters rejected by `RealityBuilder::build`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuildError {
+    /// Fewer than two cells per axis leave no grid spacing
+    Resolution(usize),
+    /// Bounds must be finite with min < max
+    Bounds(f64, f64),
+    /// Time step must be finite and positive
+    Dt(f64),
+    /// Diffusion must be finite and non-negative
+    Diffusion(f64),
+    /// Stability number above `MAX_STABILITY_NUMBER` (or `MAX_STABILITY_NUMBER_2D`)
+    Unstable(f64),
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::Resolution(resolution) => write!(f, "resolution must be at least 2, got {}", resolution),
+            BuildError::Bounds(min, max) => write!(f, "bounds must satisfy min < max, got ({}, {})", min, max),
+            BuildError::Dt(dt) => write!(f, "dt must be positive, got {}", dt),
+            BuildError::Diffusion(diffusion) => write!(f, "diffusion must be non-negative, got {}", diffusion),
+            BuildError::Unstable(number) => write!(
+                f, "stability number D·dt/Δx² = {} exceeds the explicit limit; reduce dt or diffusion",
+                number),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// A position off the grid, returned by `Reality::information_at_checked`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutOfBounds {
+    /// The offending position
+    pub position: (f64, f64, f64),
+    /// The grid's (min, max) along x, y and z
+    pub bounds: Extents,
+}
+
+impl std::fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (x, y, z) = self.position;
+        let ((x0, x1), (y0, y1), (z0, z1)) = self.bounds;
+        write!(
+            f, "position ({}, {}, {}) lies outside the grid bounds x ({}, {}), y ({}, {}), z ({}, {})",
+            x, y, z, x0, x1, y0, y1, z0, z1)
+    }
+}
+
+impl std::error::Error for OutOfBounds {}
+
+/// Named-parameter construction of a Reality
+/// 
+/// Unset parameters take the crate defaults (`DEFAULT_*`, current cosmic age).
+/// 
+/// ```
+/// use iirt_engine::RealityBuilder;
+/// 
+/// let reality = RealityBuilder::new()
+///     .resolution(24)
+///     .bounds((-1.0, 1.0))
+///     .diffusion(0.8)
+///     .dt(0.005)
+///     .build()
+///     .unwrap();
+/// assert_eq!(reality.grid_point_count(), 24 * 24 * 24);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealityBuilder {
     resolution: usize,
     bounds: (f64, f64),
     diffusion: f64,
     dt: f64,
-    time: f64,
-    step: u64,
     cosmic_age: f64,
+    planar: bool,
+    shape: Option<(usize, usize, usize)>,
+    extents: Option<Extents>,
+    /// Reject an unstable explicit scheme (off when restoring saved state)
+    check_stability: bool,
+}
+
+impl Default for RealityBuilder {
+    fn default() -> Self {
+        Self {
+            resolution: DEFAULT_RESOLUTION,
+            bounds: DEFAULT_BOUNDS,
+            diffusion: DEFAULT_DIFFUSION,
+            dt: DEFAULT_DT,
+            cosmic_age: CURRENT_COSMIC_AGE_GYR,
+            planar: false,
+            shape: None,
+            extents: None,
+            check_stability: true,
+        }
+    }
+}
+
+impl RealityBuilder {
+    /// Start from the default parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Cells per axis
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+    
+    /// (min, max) extent of the cubic domain on every axis
+    pub fn bounds(mut self, bounds: (f64, f64)) -> Self {
+        self.bounds = bounds;
+        self
+    }
+    
+    /// Diffusion coefficient D
+    pub fn diffusion(mut self, diffusion: f64) -> Self {
+        self.diffusion = diffusion;
+        self
+    }
+    
+    /// Time step
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = dt;
+        self
+    }
+    
+    /// Cosmic age (Gyr) setting the vacuum density
+    pub fn cosmic_age(mut self, cosmic_age: f64) -> Self {
+        self.cosmic_age = cosmic_age;
+        self
+    }
+    
+    /// Two-dimensional N×N grid in the z = 0 plane instead of an N³ cube
+    pub fn planar(mut self, planar: bool) -> Self {
+        self.planar = planar;
+        self
+    }
+    
+    /// Cells along (x, y, z) of a rectangular grid, overriding `resolution`
+    /// 
+    /// On a 2D field the z count is ignored.
+    pub fn shape(mut self, shape: (usize, usize, usize)) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+    
+    /// (min, max) along x, y and z of a rectangular domain, overriding `bounds`
+    pub fn extents(mut self, extents: Extents) -> Self {
+        self.extents = Some(extents);
+        self
+    }
+    
+    /// Accept a stability number past the explicit limit
+    /// 
+    /// For restoring saved fields and configs: `set_dt_unchecked` may
+    /// legitimately have raised dt past the limit for `evolve_implicit`.
+    pub(crate) fn skip_stability_check(mut self) -> Self {
+        self.check_stability = false;
+        self
+    }
+    
+    /// Validate the parameters and create the vacuum field
+    /// 
+    /// Besides the range checks, rejects an unstable explicit scheme
+    /// (see `Reality::stability_number`).
+    pub fn build(&self) -> Result<Reality, BuildError> {
+        let (n, bounds) = (self.resolution, self.bounds);
+        let (nx, ny, nz) = self.shape.unwrap_or((n, n, n));
+        let shape = (nx, ny, if self.planar { 1 } else { nz });
+        let extents = self.extents.unwrap_or((bounds, bounds, bounds));
+        let counts: &[usize] = if self.planar { &[nx, ny] } else { &[nx, ny, nz] };
+        if let Some(&count) = counts.iter().find(|&&count| count < 2) {
+            return Err(BuildError::Resolution(count));
+        }
+        for (min_bound, max_bound) in [extents.0, extents.1, extents.2] {
+            if !min_bound.is_finite() || !max_bound.is_finite() || min_bound >= max_bound {
+                return Err(BuildError::Bounds(min_bound, max_bound));
+            }
+        }
+        if !self.dt.is_finite() || self.dt <= 0.0 {
+            return Err(BuildError::Dt(self.dt));
+        }
+        if !self.diffusion.is_finite() || self.diffusion < 0.0 {
+            return Err(BuildError::Diffusion(self.diffusion));
+        }
+        let limit = if self.planar { MAX_STABILITY_NUMBER_2D } else { MAX_STABILITY_NUMBER };
+        let number = self.diffusion * self.dt * mean_spacing_weight(shape, extents, self.planar);
+        if self.check_stability && number > limit {
+            return Err(BuildError::Unstable(number));
+        }
+        
+        let vacuum = vacuum_at_cosmic_time(self.cosmic_age);
+        let mut reality = Reality {
+            field: vec![Information::new(vacuum); shape.0 * shape.1 * shape.2],
+            shape,
+            extents,
+            diffusion: self.diffusion,
+            dt: self.dt,
+            time: 0.0,
+            step: 0,
+            cosmic_age: self.cosmic_age,
+            total_information: 0.0,
+            conscious_count: 0,
+            recording: None,
+            probes: Vec::new(),
+            history_every: 0,
+            history: Vec::new(),
+            creation: None,
+            pins: Vec::new(),
+            dt_limits: (0.0, f64::INFINITY),
+            totals_stale: false,
+            diffusion_xyz: None,
+            last_balance: InfoBalance::default(),
+            last_residual: 0.0,
+            max_information: MAX_INFORMATION,
+            uncertainty_fn: None,
+            clamping: false,
+            planar: self.planar,
+        };
+        reality.recount();
+        Ok(reality)
+    }
+}
+
+/// 3D Information field implementing IIRT dynamics
+#[derive(Clone)]
+pub struct Reality {
+    pub(crate) field: Vec<Information>,
+    /// Cells along (x, y, z); z is 1 on a 2D field
+    pub(crate) shape: (usize, usize, usize),
+    pub(crate) extents: Extents,
+    pub(crate) diffusion: f64,
+    pub(crate) dt: f64,
+    pub(crate) time: f64,
+    pub(crate) step: u64,
+    pub(crate) cosmic_age: f64,
+    total_information: f64,
+    conscious_count: usize,
+    /// Config when recording started and the ops logged since
+    pub(crate) recording: Option<(RealityConfig, Vec<Op>)>,
+    pub(crate) probes: Vec<ProbeHistory>,
+    /// Record a `HistoryFrame` every this many steps (0: off)
+    history_every: usize,
+    history: Vec<HistoryFrame>,
+    /// Per-cell information created since `enable_creation_tracking` (None: off)
+    creation: Option<Vec<f64>>,
+    /// Cells held by `pin_region` as (index, density), sorted by index
+    pub(crate) pins: Vec<(usize, Information)>,
+    /// (min, max) dt allowed to `evolve_adaptive`
+    dt_limits: (f64, f64),
+    /// Running totals invalidated by `iter_cells_mut`
+    totals_stale: bool,
+    /// Per-axis diffusion set by `set_diffusion_xyz` (None: isotropic)
+    pub(crate) diffusion_xyz: Option<(f64, f64, f64)>,
+    /// Budget of the last `evolve`/`evolve_backward` step
+    last_balance: InfoBalance,
+    /// Largest per-cell |Δℐ| of the last `evolve`/`evolve_backward` step
+    last_residual: f64,
+    /// Saturation density of the logistic term (see `set_max_information`)
+    max_information: f64,
+    /// Custom ε(ℐ) from `set_uncertainty_fn` (None: `Information::uncertainty`)
+    uncertainty_fn: Option<Arc<dyn Fn(f64) -> f64 + Send + Sync>>,
+    /// Clamp the whole field after every step (see `set_clamping`)
+    pub(crate) clamping: bool,
+    /// N×N grid in the z = 0 plane (see `new_2d`)
+    pub(crate) planar: bool,
 }
 
 impl Reality {
     /// Create new reality field
+    /// 
+    /// Panics on parameters `RealityBuilder::build` rejects.
     pub fn new(resolution: usize, bounds: (f64, f64), diffusion: f64, dt: f64) -> Self {
-        Self::new_at_cosmic_age(resolution, bounds, diffusion, dt, CURRENT_COSMIC_AGE_GYR)
+        Self::new_rect((resolution, resolution, resolution), (bounds, bounds, bounds), diffusion, dt)
+    }
+    
+    /// Create a rectangular reality: nx×ny×nz cells over per-axis bounds
+    /// 
+    /// Slabs and columns without paying for a cube. Each axis has its own
+    /// spacing Δxₐ. `diffusion` is in x-grid units as for `new` (physical
+    /// coefficient D·Δx²), so the Laplacian weighs the second difference
+    /// along axis a by (Δx/Δxₐ)², and the stability limit applies to D·dt
+    /// times the mean weight. Uses the current cosmic age.
+    /// 
+    /// Panics on parameters `RealityBuilder::build` rejects.
+    pub fn new_rect(resolution: (usize, usize, usize), bounds: Extents, diffusion: f64, dt: f64) -> Self {
+        RealityBuilder::new()
+            .shape(resolution)
+            .extents(bounds)
+            .diffusion(diffusion)
+            .dt(dt)
+            .build()
+            .unwrap_or_else(|error| panic!("invalid Reality parameters: {}", error))
     }
     
     /// Create reality at specific cosmic age
+    /// 
+    /// Panics on parameters `RealityBuilder::build` rejects.
     pub fn new_at_cosmic_age(resolution: usize, bounds: (f64, f64), diffusion: f64, dt: f64, cosmic_age: f64) -> Self {
-        let size = resolution * resolution * resolution;
-        let vacuum = vacuum_at_cosmic_time(cosmic_age);
-        let field = vec![Information::new(vacuum); size];
-        
-        Self {
-            field,
-            resolution,
-            bounds,
-            diffusion,
-            dt,
-            time: 0.0,
-            step: 0,
-            cosmic_age,
-        }
+        RealityBuilder::new()
+            .resolution(resolution)
+            .bounds(bounds)
+            .diffusion(diffusion)
+            .dt(dt)
+            .cosmic_age(cosmic_age)
+            .build()
+            .unwrap_or_else(|error| panic!("invalid Reality parameters: {}", error))
+    }
+    
+    /// Create a two-dimensional reality: an N×N grid in the z = 0 plane
+    /// 
+    /// Runs the 2D master equation (four-neighbour Laplacian) at the current
+    /// cosmic age. Memory and work per step scale as N² instead of N³ - 1/N of
+    /// the cube, e.g. 64² = 4096 cells against 64³ = 262144. Positions keep
+    /// three coordinates; z is ignored. The explicit step is stable up to
+    /// D·dt ≤ `MAX_STABILITY_NUMBER_2D`.
+    /// 
+    /// Panics on parameters `RealityBuilder::build` rejects.
+    pub fn new_2d(resolution: usize, bounds: (f64, f64), diffusion: f64, dt: f64) -> Self {
+        RealityBuilder::new()
+            .resolution(resolution)
+            .bounds(bounds)
+            .diffusion(diffusion)
+            .dt(dt)
+            .planar(true)
+            .build()
+            .unwrap_or_else(|error| panic!("invalid Reality parameters: {}", error))
     }
     
     /// Create vacuum reality (current cosmic age)
@@ -96,202 +674,4398 @@ impl Reality {
     }
     
     /// Add information at position
+    /// 
+    /// A negative amplitude still works but is deprecated as a sink: it can
+    /// push the cell below vacuum. Use `remove_information`, or
+    /// `set_density` for a deliberate sub-vacuum value.
     pub fn add_information(&mut self, position: (f64, f64, f64), amplitude: f64) {
+        self.log(Op::AddInformation { position, amplitude });
         if let Ok(idx) = self.position_to_index(position) {
             let current = self.field[idx].density();
-            self.field[idx] = Information::new(current + amplitude);
+            self.set_cell(idx, self.information(current + amplitude));
         }
     }
     
-    /// Evolve one time step: ∂ℐ/∂t = D∇²ℐ - ε²ℐ + ℐ(1-ℐ/ℐ_max)
-    pub fn evolve(&mut self) {
-        let mut new_field = self.field.clone();
-        
-        #[cfg(feature = "parallel")]
-        {
-            // Parallel version using rayon
-            let resolution = self.resolution;
-            let diffusion = self.diffusion;
-            let dt = self.dt;
-            let field = &self.field;
-            
-            let indices: Vec<_> = (1..resolution-1)
-                .flat_map(|i| (1..resolution-1)
-                    .flat_map(move |j| (1..resolution-1)
-                        .map(move |k| (i, j, k))))
-                .collect();
-            
-            let updates: Vec<_> = indices.par_iter().map(|&(i, j, k)| {
-                let idx = k * resolution * resolution + j * resolution + i;
-                let info = field[idx];
-                
-                // Calculate laplacian
-                let center = field[idx].density();
-                let neighbors = [
-                    field[k * resolution * resolution + j * resolution + (i-1)].density(),
-                    field[k * resolution * resolution + j * resolution + (i+1)].density(),
-                    field[k * resolution * resolution + (j-1) * resolution + i].density(),
-                    field[k * resolution * resolution + (j+1) * resolution + i].density(),
-                    field[(k-1) * resolution * resolution + j * resolution + i].density(),
-                    field[(k+1) * resolution * resolution + j * resolution + i].density(),
-                ];
-                let laplacian = neighbors.iter().sum::<f64>() - 6.0 * center;
-                
-                // IIRT equation
-                let diffusion_term = diffusion * laplacian;
-                let intrinsic_term = info.intrinsic_rate();
-                let change = diffusion_term + intrinsic_term;
-                
-                (idx, Information::new(info.density() + dt * change))
-            }).collect();
-            
-            for (idx, new_info) in updates {
-                new_field[idx] = new_info;
-            }
+    /// Remove up to `amount` bits at position, stopping at the vacuum floor
+    /// 
+    /// A cell already below vacuum is left unchanged. Panics if `amount` is
+    /// negative.
+    pub fn remove_information(&mut self, position: (f64, f64, f64), amount: f64) {
+        assert!(amount >= 0.0, "remove_information requires amount >= 0");
+        self.log(Op::RemoveInformation { position, amount });
+        if let Ok(idx) = self.position_to_index(position) {
+            let current = self.field[idx].density();
+            let floor = self.vacuum_density().min(current);
+            self.set_cell(idx, Information((current - amount).max(floor)));
         }
-        
-        #[cfg(not(feature = "parallel"))]
-        {
-            // Sequential version - same calculation as parallel
-            for i in 1..self.resolution-1 {
-                for j in 1..self.resolution-1 {
-                    for k in 1..self.resolution-1 {
-                        let idx = self.index(i, j, k);
-                        let info = self.field[idx];
-                        
-                        // Calculate laplacian (same as parallel version)
-                        let center = self.field[idx].density();
-                        let neighbors = [
-                            self.field[k * self.resolution * self.resolution + j * self.resolution + (i-1)].density(),
-                            self.field[k * self.resolution * self.resolution + j * self.resolution + (i+1)].density(),
-                            self.field[k * self.resolution * self.resolution + (j-1) * self.resolution + i].density(),
-                            self.field[k * self.resolution * self.resolution + (j+1) * self.resolution + i].density(),
-                            self.field[(k-1) * self.resolution * self.resolution + j * self.resolution + i].density(),
-                            self.field[(k+1) * self.resolution * self.resolution + j * self.resolution + i].density(),
-                        ];
-                        let laplacian = neighbors.iter().sum::<f64>() - 6.0 * center;
-                        
-                        // IIRT equation
-                        let diffusion_term = self.diffusion * laplacian;
-                        let intrinsic_term = info.intrinsic_rate();
-                        let change = diffusion_term + intrinsic_term;
-                        
-                        new_field[idx] = Information::new(info.density() + self.dt * change);
-                    }
-                }
+    }
+    
+    /// Set the density at position to `value`
+    /// 
+    /// Panics unless `value` lies in [0, ℐ_max].
+    pub fn set_density(&mut self, position: (f64, f64, f64), value: f64) {
+        assert!(
+            (0.0..=self.max_information).contains(&value),
+            "set_density requires 0 <= value <= max_information, got {}", value
+        );
+        self.log(Op::SetDensity { position, value });
+        if let Ok(idx) = self.position_to_index(position) {
+            self.set_cell(idx, Information(value));
+        }
+    }
+    
+    /// Deposit `amplitude` bits as a Gaussian of width `sigma` around `center`
+    /// 
+    /// Spreads over the cells within 3σ with weights exp(-r²/2σ²), normalized
+    /// on the grid so the deposit totals `amplitude` - the same amount
+    /// `add_information` puts into one cell - unless clamping intervenes.
+    /// If no cell lies within 3σ the nearest one takes it all. z is ignored
+    /// on a 2D field. Panics unless sigma > 0.
+    pub fn add_gaussian(&mut self, center: (f64, f64, f64), amplitude: f64, sigma: f64) {
+        assert!(sigma > 0.0, "add_gaussian requires sigma > 0");
+        self.log(Op::AddGaussian { center, amplitude, sigma });
+        let distance_sq = |(x, y, z): (f64, f64, f64)| {
+            let dz = if self.planar { 0.0 } else { z - center.2 };
+            (x - center.0).powi(2) + (y - center.1).powi(2) + dz * dz
+        };
+        let mut cells: Vec<(usize, f64)> = (0..self.field.len())
+            .map(|idx| (idx, distance_sq(self.index_position(idx))))
+            .filter(|&(_, r_sq)| r_sq <= 9.0 * sigma * sigma)
+            .collect();
+        // Narrower than the grid: the nearest cell takes everything
+        if cells.is_empty() {
+            if let Ok(idx) = self.position_to_index(center) {
+                cells.push((idx, 0.0));
             }
         }
+        // Weights relative to the nearest cell cannot all underflow
+        let nearest = cells.iter().map(|&(_, r_sq)| r_sq).fold(f64::INFINITY, f64::min);
+        let weights: Vec<f64> = cells.iter().map(|&(_, r_sq)| (-(r_sq - nearest) / (2.0 * sigma * sigma)).exp()).collect();
+        let total: f64 = weights.iter().sum();
         
-        self.field = new_field;
-        self.time += self.dt;
-        self.step += 1;
+        for (&(idx, _), weight) in cells.iter().zip(weights) {
+            let current = self.field[idx].density();
+            self.set_cell(idx, self.information(current + amplitude * weight / total));
+        }
     }
     
-    /// Get information at position
-    pub fn information_at(&self, position: (f64, f64, f64)) -> Option<Information> {
-        self.position_to_index(position).ok().map(|idx| self.field[idx])
+    /// Add a smooth spherical shell: amplitude·exp(-(r-radius)²/2·thickness²)
+    /// 
+    /// `amplitude` is the peak added at the shell radius (unlike
+    /// `add_gaussian`, the total is not normalized). Cells more than
+    /// 3·thickness from the shell are untouched. On a 2D field the shell is a
+    /// ring in the plane. Panics unless thickness > 0.
+    pub fn add_spherical_shell(&mut self, center: (f64, f64, f64), radius: f64, amplitude: f64, thickness: f64) {
+        assert!(thickness > 0.0, "add_spherical_shell requires thickness > 0");
+        self.log(Op::AddSphericalShell { center, radius, amplitude, thickness });
+        for idx in 0..self.field.len() {
+            let (x, y, z) = self.index_position(idx);
+            let dz = if self.planar { 0.0 } else { z - center.2 };
+            let offset = ((x - center.0).powi(2) + (y - center.1).powi(2) + dz * dz).sqrt() - radius;
+            if offset.abs() <= 3.0 * thickness {
+                let current = self.field[idx].density();
+                let added = amplitude * (-offset * offset / (2.0 * thickness * thickness)).exp();
+                self.set_cell(idx, self.information(current + added));
+            }
+        }
     }
     
-    /// Total information in field
-    pub fn total_information(&self) -> f64 {
-        #[cfg(feature = "parallel")]
-        {
-            self.field.par_iter().map(|i| i.density()).sum()
-        }
-        #[cfg(not(feature = "parallel"))]
-        {
-            self.field.iter().map(|i| i.density()).sum()
+    /// Set a linear density ramp from `low` to `high` along `axis`
+    /// 
+    /// Every cell whose `axis` coordinate lies in `region` (inclusive,
+    /// either order) is overwritten with the ramp value at that coordinate;
+    /// the ramp is constant across the other axes. Values are clamped to
+    /// [0, ℐ_max]. Panics for `Axis::Z` on a 2D field or an empty region.
+    pub fn add_linear_gradient(&mut self, axis: Axis, low: f64, high: f64, region: (f64, f64)) {
+        assert!(!(self.planar && axis == Axis::Z), "a 2D field has no z extent");
+        assert!(region.0 != region.1, "add_linear_gradient requires a non-empty region");
+        self.log(Op::LinearGradient { axis, low, high, region });
+        let (start, end) = region;
+        for idx in 0..self.field.len() {
+            let (x, y, z) = self.index_position(idx);
+            let coordinate = match axis {
+                Axis::X => x,
+                Axis::Y => y,
+                Axis::Z => z,
+            };
+            let fraction = (coordinate - start) / (end - start);
+            if (0.0..=1.0).contains(&fraction) {
+                self.set_cell(idx, self.information(low + (high - low) * fraction));
+            }
         }
     }
     
-    /// Count conscious points
-    pub fn conscious_count(&self) -> usize {
-        #[cfg(feature = "parallel")]
-        {
-            self.field.par_iter().filter(|i| i.is_conscious()).count()
+    /// Add uniform pseudorandom noise in [-amplitude, amplitude] to every cell
+    /// 
+    /// Reproducible: the same seed gives the same perturbation. Densities
+    /// stay clamped to [0, ℐ_max].
+    pub fn add_noise(&mut self, amplitude: f64, seed: u64) {
+        self.log(Op::AddNoise { amplitude, seed });
+        let mut rng = SplitMix64::new(seed);
+        let max_information = self.max_information;
+        for cell in self.field.iter_mut() {
+            *cell = Information((cell.density() + rng.range(-amplitude, amplitude)).clamp(0.0, max_information));
         }
-        #[cfg(not(feature = "parallel"))]
-        {
-            self.field.iter().filter(|i| i.is_conscious()).count()
+        self.recount();
+    }
+    
+    /// Set the time step used by subsequent `evolve` calls
+    /// 
+    /// Panics unless dt is finite and positive, or if it takes
+    /// `stability_number` past the explicit limit - the checks
+    /// `RealityBuilder::build` reports as `BuildError::Dt` and
+    /// `BuildError::Unstable`. Use `set_dt_unchecked` for `evolve_implicit`.
+    pub fn set_dt(&mut self, dt: f64) {
+        assert!(dt.is_finite() && dt > 0.0, "{}", BuildError::Dt(dt));
+        let number = self.effective_diffusion() * dt;
+        assert!(number <= self.stability_limit(), "{}", BuildError::Unstable(number));
+        self.set_dt_unchecked(dt);
+    }
+    
+    /// Set the time step without the explicit stability check
+    /// 
+    /// For `evolve_implicit`, which is stable past the explicit limit; `evolve`
+    /// at such a dt blows up. Still panics unless dt is finite and positive.
+    pub fn set_dt_unchecked(&mut self, dt: f64) {
+        assert!(dt.is_finite() && dt > 0.0, "{}", BuildError::Dt(dt));
+        self.log(Op::SetDt(dt));
+        self.dt = dt;
+    }
+    
+    /// Clamp the time steps `evolve_adaptive` may choose to [min_dt, max_dt]
+    /// 
+    /// The CFL limit applies on top of `max_dt`. Defaults to (0, ∞).
+    pub fn set_dt_limits(&mut self, min_dt: f64, max_dt: f64) {
+        assert!(min_dt >= 0.0 && min_dt <= max_dt, "dt limits require 0 <= min_dt <= max_dt");
+        self.dt_limits = (min_dt, max_dt);
+    }
+    
+    /// Set the diffusion coefficient used by subsequent `evolve` calls
+    /// 
+    /// Isotropic: replaces any per-axis coefficients.
+    pub fn set_diffusion(&mut self, diffusion: f64) {
+        self.log(Op::SetDiffusion(diffusion));
+        self.diffusion = diffusion;
+        self.diffusion_xyz = None;
+    }
+    
+    /// Set the saturation density ℐ_max of the logistic term ℐ(1-ℐ/ℐ_max)
+    /// 
+    /// Per instance; defaults to `MAX_INFORMATION`. Densities are clamped to
+    /// [0, i_max] from now on, including those already above it. Kept by
+    /// `save` and `checkpoint`. Panics unless i_max > `INTEGRATION_THRESHOLD`.
+    pub fn set_max_information(&mut self, i_max: f64) {
+        assert!(i_max > INTEGRATION_THRESHOLD, "max information must exceed the integration threshold");
+        self.log(Op::SetMaxInformation(i_max));
+        self.max_information = i_max;
+        for cell in self.field.iter_mut() {
+            *cell = Information(cell.density().min(i_max));
         }
+        self.recount();
     }
     
-    /// Check if any point is conscious
-    pub fn is_conscious(&self) -> bool {
-        self.conscious_count() > 0
+    /// Replace the uncertainty ε(ℐ) in the -ε²ℐ decay term
+    /// 
+    /// `f` maps density to ε and is used by every integrator from now on.
+    /// The default is `Information::uncertainty`, 0.5/(1+ℐ) clamped at
+    /// `MIN_UNCERTAINTY`. A closure cannot be recorded, so the op log and
+    /// saved files keep assuming the default.
+    pub fn set_uncertainty_fn(&mut self, f: Box<dyn Fn(f64) -> f64 + Send + Sync>) {
+        self.uncertainty_fn = Some(Arc::from(f));
     }
     
-    /// Current vacuum density
-    pub fn vacuum_density(&self) -> f64 {
-        vacuum_at_cosmic_time(self.cosmic_age)
+    /// Clamp every cell to [0, ℐ_max] after each step (default off)
+    /// 
+    /// Integrators already clamp the cells they update; this also covers the
+    /// fixed faces and values written through `iter_cells_mut`, and resets
+    /// NaN to 0, so long runs keep the conscious count meaningful. Not
+    /// recorded in the op log, but kept by `save` and `checkpoint`.
+    pub fn set_clamping(&mut self, enabled: bool) {
+        self.clamping = enabled;
     }
     
-    /// Information created above vacuum
-    pub fn information_created(&self) -> f64 {
-        let vacuum_total = self.vacuum_density() * self.field.len() as f64;
-        self.total_information() - vacuum_total
+    /// Whether any cell is NaN, infinite, negative or above ℐ_max
+    pub fn has_nonphysical_values(&self) -> bool {
+        self.field.iter().any(|info| !(0.0..=self.max_information).contains(&info.density()))
     }
     
-    /// Get current time
-    pub fn time(&self) -> f64 { self.time }
+    /// Whether every cell is finite (no NaN or infinity)
+    pub fn is_finite(&self) -> bool {
+        self.field.iter().all(|info| info.density().is_finite())
+    }
     
-    /// Get step count  
-    pub fn step(&self) -> u64 { self.step }
+    /// Largest cell density; NaN cells are skipped
+    pub fn max_density(&self) -> f64 {
+        self.field.iter().map(Information::density).fold(f64::NEG_INFINITY, f64::max)
+    }
     
-    /// Get cosmic age
-    pub fn cosmic_age(&self) -> f64 { self.cosmic_age }
+    /// Saturation density ℐ_max used by this field
+    pub fn max_information(&self) -> f64 {
+        self.max_information
+    }
     
-    // Private helpers
+    /// Set a diagonal diffusion tensor: separate coefficients along x, y and z
+    /// 
+    /// Each second derivative of the Laplacian is weighted by its axis'
+    /// coefficient. `diffusion` (and so `config`) reports the mean; saved
+    /// files keep all three. `set_diffusion` returns to the isotropic default.
+    pub fn set_diffusion_xyz(&mut self, diffusion_x: f64, diffusion_y: f64, diffusion_z: f64) {
+        self.log(Op::SetDiffusionXyz(diffusion_x, diffusion_y, diffusion_z));
+        self.diffusion = (diffusion_x + diffusion_y + diffusion_z) / 3.0;
+        self.diffusion_xyz = Some((diffusion_x, diffusion_y, diffusion_z));
+    }
     
-    fn index(&self, i: usize, j: usize, k: usize) -> usize {
-        k * self.resolution * self.resolution + j * self.resolution + i
+    /// Attach a probe sampling density at `position` after every step
+    /// 
+    /// Returns the probe's index into `probe_histories`.
+    pub fn add_probe(&mut self, position: (f64, f64, f64)) -> usize {
+        self.probes.push(ProbeHistory { position, samples: Vec::new() });
+        self.probes.len() - 1
     }
     
-    fn position_to_index(&self, (x, y, z): (f64, f64, f64)) -> Result<usize, ()> {
-        let (min_bound, max_bound) = self.bounds;
-        let scale = (max_bound - min_bound) / (self.resolution - 1) as f64;
-        
-        let i = ((x - min_bound) / scale).round() as usize;
-        let j = ((y - min_bound) / scale).round() as usize;
-        let k = ((z - min_bound) / scale).round() as usize;
-        
-        if i >= self.resolution || j >= self.resolution || k >= self.resolution {
-            Err(())
-        } else {
-            Ok(self.index(i, j, k))
+    /// Time series of all attached probes
+    pub fn probe_histories(&self) -> &[ProbeHistory] {
+        &self.probes
+    }
+    
+    /// Record a `HistoryFrame` after every `every`-th step
+    /// 
+    /// Frames are taken when the step count is a multiple of `every`, by
+    /// `evolve`, `evolve_rk4` and everything built on them. Clears frames
+    /// already recorded; `every = 0` turns recording off.
+    pub fn enable_history(&mut self, every: usize) {
+        self.history_every = every;
+        self.history.clear();
+    }
+    
+    /// Frames recorded since `enable_history`, oldest first
+    pub fn history(&self) -> &[HistoryFrame] {
+        &self.history
+    }
+    
+    /// Start accumulating the information created in every cell
+    /// 
+    /// Each `evolve`/`evolve_backward` step adds the local source minus
+    /// sink h·(ℐ(1-ℐ/ℐ_max) - ε²ℐ) of every updated cell, the per-cell
+    /// split of `information_balance`; diffusion only moves information and
+    /// is not counted. Restarts from zero if already tracking.
+    pub fn enable_creation_tracking(&mut self) {
+        self.creation = Some(vec![0.0; self.field.len()]);
+    }
+    
+    /// Information created since `enable_creation_tracking` by the cells
+    /// whose centers lie in the box `min`..=`max`
+    /// 
+    /// In bits summed over cells, like `information_balance`; over the whole
+    /// grid it is the sum of source - sink of every tracked step. z is
+    /// ignored on a 2D field. Panics unless creation tracking is enabled.
+    pub fn information_created_in_box(&self, min: (f64, f64, f64), max: (f64, f64, f64)) -> f64 {
+        let creation = self.creation.as_ref()
+            .expect("information_created_in_box requires enable_creation_tracking");
+        creation.iter().enumerate()
+            .filter(|&(idx, _)| self.box_contains(min, max, self.index_position(idx)))
+            .map(|(_, created)| created)
+            .sum()
+    }
+    
+    /// 64-bit fingerprint of the densities and grid parameters
+    /// 
+    /// FNV-1a over the shape, extents, D, dt, cosmic age, ℐ_max, step count
+    /// and every density rounded to a multiple of `FINGERPRINT_QUANTUM`.
+    /// Equal states hash equal on every platform, so a regression test can
+    /// pin the exact outcome of a run; a density sitting on a rounding
+    /// boundary can still flip it under sub-quantum changes.
+    pub fn state_fingerprint(&self) -> u64 {
+        let (nx, ny, nz) = self.shape;
+        let ((x0, x1), (y0, y1), (z0, z1)) = self.extents;
+        let parameters = [nx as u64, ny as u64, nz as u64, self.step]
+            .into_iter()
+            .chain([x0, x1, y0, y1, z0, z1, self.diffusion, self.dt, self.cosmic_age, self.max_information].map(f64::to_bits));
+        let densities = self.field.iter().map(|info| (info.density() / FINGERPRINT_QUANTUM).round() as i64 as u64);
+        parameters.chain(densities).fold(0xCBF2_9CE4_8422_2325, |hash, word| {
+            word.to_le_bytes().iter().fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3))
+        })
+    }
+    
+    /// Grid parameters as a config (no deposits, no noise)
+    pub fn config(&self) -> RealityConfig {
+        RealityConfig {
+            resolution: self.shape.0,
+            bounds: self.extents.0,
+            diffusion: self.diffusion,
+            dt: self.dt,
+            cosmic_age: self.cosmic_age,
+            deposits: Vec::new(),
+            noise: 0.0,
+            planar: self.planar,
+            shape: (!self.is_cubic() && !self.planar).then_some(self.shape),
+            extents: (self.extents != (self.extents.0, self.extents.0, self.extents.0)).then_some(self.extents),
         }
     }
     
-
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Capture the density grid, time, step count and running totals
+    /// 
+    /// Restoring it later gives a bit-identical starting point, so several
+    /// measurements can branch from one state without re-simulating.
+    pub fn snapshot(&self) -> RealitySnapshot {
+        RealitySnapshot {
+            field: self.field.clone(),
+            time: self.time,
+            step: self.step,
+            total_information: self.total_information(),
+            conscious_count: self.conscious_count(),
+        }
+    }
     
-    #[test]
-    fn test_consciousness_threshold() {
-        assert!(!Information::new(0.5).is_conscious());
-        assert!(Information::new(INTEGRATION_THRESHOLD).is_conscious());
-        assert!(Information::new(1.0).is_conscious());
+    /// Return to a state captured by `snapshot`
+    /// 
+    /// Grid parameters, probes and recording are left as they are.
+    /// Panics if the snapshot was taken at a different resolution.
+    pub fn restore(&mut self, snapshot: &RealitySnapshot) {
+        assert_eq!(self.field.len(), snapshot.field.len(), "restore requires a matching resolution");
+        self.field.clone_from(&snapshot.field);
+        self.time = snapshot.time;
+        self.step = snapshot.step;
+        self.total_information = snapshot.total_information;
+        self.conscious_count = snapshot.conscious_count;
+        self.totals_stale = false;
     }
     
-    #[test]
-    fn test_information_creation() {
-        let info = Information::new(1.0);
-        assert!(info.intrinsic_rate() > 0.0);
+    /// Refill the grid with the cosmic-age vacuum and rewind to time 0, in place
+    /// 
+    /// For parameter sweeps that reuse one allocation. Step count, time,
+    /// history and probe samples are cleared; dt, diffusion, ℐ_max, probe
+    /// positions and other settings are kept.
+    pub fn reset_to_vacuum(&mut self) {
+        self.log(Op::ResetToVacuum);
+        let vacuum = self.information(self.vacuum_density());
+        self.field.fill(vacuum);
+        self.time = 0.0;
+        self.step = 0;
+        self.history.clear();
+        self.last_balance = InfoBalance::default();
+        self.last_residual = 0.0;
+        if let Some(creation) = &mut self.creation {
+            creation.fill(0.0);
+        }
+        for probe in &mut self.probes {
+            probe.samples.clear();
+        }
+        self.recount();
     }
     
-    #[test]
-    fn test_iirt_equation() {
-        let mut reality = Reality::from_vacuum();
-        reality.add_information((0.0, 0.0, 0.0), 2.0);
-        
-        let initial = reality.total_information();
-        reality.evolve();
-        let final_info = reality.total_information();
-        
-        assert!(final_info > initial);
-        assert!(reality.conscious_count() > 0);
+    /// Advance the cosmic epoch by `delta_gyr`, raising the vacuum with it
+    /// 
+    /// The vacuum follows ℐ_vac(t) = ℐ_threshold·e^(αt); every cell, faces
+    /// included, gains the uniform rise ℐ_vac(t + Δt) - ℐ_vac(t), clamped to
+    /// [0, ℐ_max]. Structure thus rides on the grown background and
+    /// `information_created` is unchanged up to clamping. Simulation `time`
+    /// is not advanced. Panics unless delta_gyr ≥ 0.
+    pub fn advance_cosmic_time(&mut self, delta_gyr: f64) {
+        assert!(delta_gyr >= 0.0, "advance_cosmic_time requires delta_gyr >= 0");
+        self.log(Op::AdvanceCosmicTime(delta_gyr));
+        let rise = vacuum_at_cosmic_time(self.cosmic_age + delta_gyr) - self.vacuum_density();
+        self.cosmic_age += delta_gyr;
+        let max_information = self.max_information;
+        for cell in self.field.iter_mut() {
+            *cell = Information((cell.density() + rise).clamp(0.0, max_information));
+        }
+        self.recount();
     }
-}
\ No newline at end of file
+    
+    /// Hold every cell in the box `min`..=`max` at `density` (a Dirichlet source)
+    /// 
+    /// The cells are set now and reset after every step of `evolve`,
+    /// `evolve_rk4`, `evolve_backward` and `evolve_implicit`, so a pinned
+    /// region keeps feeding (or draining) its surroundings instead of being
+    /// re-injected by hand. Pinning a cell again replaces its density. The
+    /// reset lies outside `information_balance`. z is ignored on a 2D field;
+    /// `density` is clamped to [0, ℐ_max]. Pins are kept by `save`,
+    /// `checkpoint` and `save_bundle`, so a resumed run keeps its sources.
+    pub fn pin_region(&mut self, min: (f64, f64, f64), max: (f64, f64, f64), density: f64) {
+        self.log(Op::PinRegion { region: (min, max), density });
+        let info = self.information(density);
+        let cells: Vec<usize> = (0..self.field.len())
+            .filter(|&idx| self.box_contains(min, max, self.index_position(idx)))
+            .collect();
+        self.pins.retain(|(idx, _)| cells.binary_search(idx).is_err());
+        self.pins.extend(cells.iter().map(|&idx| (idx, info)));
+        self.pins.sort_unstable_by_key(|&(idx, _)| idx);
+        self.apply_pins();
+    }
+    
+    /// Release the pinned cells in the box `min`..=`max`
+    /// 
+    /// They keep their current density and evolve freely from the next step.
+    pub fn unpin_region(&mut self, min: (f64, f64, f64), max: (f64, f64, f64)) {
+        self.log(Op::UnpinRegion((min, max)));
+        let pins = std::mem::take(&mut self.pins);
+        self.pins = pins.into_iter()
+            .filter(|&(idx, _)| !self.box_contains(min, max, self.index_position(idx)))
+            .collect();
+    }
+    
+    /// Number of cells currently held by `pin_region`
+    pub fn pinned_count(&self) -> usize {
+        self.pins.len()
+    }
+    
+    /// Start logging every `add_information`, `evolve` and parameter change
+    /// 
+    /// Discards any previous log. Other mutators (solvers, annealing) are
+    /// not recorded; a log replays exactly only if it covers all changes.
+    /// The current `config()` is kept as the replay starting point (see
+    /// `recording_config`).
+    pub fn start_recording(&mut self) {
+        self.recording = Some((self.config(), Vec::new()));
+    }
+    
+    /// Stop recording and return the log
+    pub fn stop_recording(&mut self) -> Vec<Op> {
+        self.recording.take().map(|(_, ops)| ops).unwrap_or_default()
+    }
+    
+    /// Operations recorded so far (empty when not recording)
+    pub fn recorded_ops(&self) -> &[Op] {
+        self.recording.as_ref().map_or(&[], |(_, ops)| ops)
+    }
+    
+    /// Config as of `start_recording` (None when not recording)
+    /// 
+    /// `replay` of the recorded ops onto it reproduces the field, provided
+    /// recording started on a freshly built one.
+    pub fn recording_config(&self) -> Option<&RealityConfig> {
+        self.recording.as_ref().map(|(config, _)| config)
+    }
+    
+    /// Reconstruct a field by applying a recorded log to a fresh `config.build()`
+    pub fn replay(config: &RealityConfig, ops: &[Op]) -> Reality {
+        let mut reality = config.build();
+        for &op in ops {
+            match op {
+                Op::AddInformation { position, amplitude } => reality.add_information(position, amplitude),
+                Op::Evolve => reality.evolve(),
+                Op::EvolveRk4 => reality.evolve_rk4(),
+                Op::EvolveBackward => reality.evolve_backward(),
+                Op::SetDt(dt) => reality.set_dt_unchecked(dt),
+                Op::SetDiffusion(diffusion) => reality.set_diffusion(diffusion),
+                Op::SetDiffusionXyz(x, y, z) => reality.set_diffusion_xyz(x, y, z),
+                Op::AddNoise { amplitude, seed } => reality.add_noise(amplitude, seed),
+                Op::RemoveInformation { position, amount } => reality.remove_information(position, amount),
+                Op::SetDensity { position, value } => reality.set_density(position, value),
+                Op::AddGaussian { center, amplitude, sigma } => reality.add_gaussian(center, amplitude, sigma),
+                Op::ResetToVacuum => reality.reset_to_vacuum(),
+                Op::EvolveImplicit => reality.evolve_implicit(),
+                Op::AddSphericalShell { center, radius, amplitude, thickness } => {
+                    reality.add_spherical_shell(center, radius, amplitude, thickness)
+                }
+                Op::LinearGradient { axis, low, high, region } => reality.add_linear_gradient(axis, low, high, region),
+                Op::SetMaxInformation(i_max) => reality.set_max_information(i_max),
+                Op::AdvanceCosmicTime(delta_gyr) => reality.advance_cosmic_time(delta_gyr),
+                Op::PinRegion { region: (min, max), density } => reality.pin_region(min, max, density),
+                Op::UnpinRegion((min, max)) => reality.unpin_region(min, max),
+            }
+        }
+        reality
+    }
+    
+    /// Add many information deposits in one call
+    /// 
+    /// Accepts the deposit lists produced by the `patterns` seed catalog.
+    /// Every position is checked first: if any lies off the grid the field
+    /// is left untouched and the first offender is returned. The result
+    /// equals calling `add_information` for each deposit in order (and is
+    /// recorded that way); with the `parallel` feature the cells are
+    /// accumulated on rayon, each cell's deposits by one task.
+    pub fn add_information_batch(&mut self, deposits: &[((f64, f64, f64), f64)]) -> Result<(), OutOfBounds> {
+        let mut indexed = deposits.iter()
+            .map(|&(position, amplitude)| {
+                self.position_to_index(position)
+                    .map(|idx| (idx, amplitude))
+                    .map_err(|()| OutOfBounds { position, bounds: self.extents })
+            })
+            .collect::<Result<Vec<(usize, f64)>, OutOfBounds>>()?;
+        for &(position, amplitude) in deposits {
+            self.log(Op::AddInformation { position, amplitude });
+        }
+        
+        // Stable sort: deposits on one cell keep their order and clamping
+        indexed.sort_by_key(|&(idx, _)| idx);
+        let accumulate = |cell: &[(usize, f64)]| {
+            let idx = cell[0].0;
+            let density = cell.iter().fold(self.field[idx].density(), |density, &(_, amplitude)| {
+                self.information(density + amplitude).density()
+            });
+            (idx, Information(density))
+        };
+        #[cfg(feature = "parallel")]
+        let updates: Vec<(usize, Information)> = indexed.par_chunk_by(|a, b| a.0 == b.0).map(accumulate).collect();
+        #[cfg(not(feature = "parallel"))]
+        let updates: Vec<(usize, Information)> = indexed.chunk_by(|a, b| a.0 == b.0).map(accumulate).collect();
+        for (idx, info) in updates {
+            self.set_cell(idx, info);
+        }
+        Ok(())
+    }
+    
+    /// Add a 2D picture, scaled by `scale`, to the grid plane nearest `plane_z`
+    /// 
+    /// `data` holds `rows`×`cols` values row by row, row 0 at the top. The
+    /// picture is stretched over the full x-y extents (columns along +x,
+    /// rows down from max y), and every cell of the plane receives the
+    /// bilinearly interpolated value at its center times `scale`, added as
+    /// by `add_information_batch` (and recorded that way). `plane_z` is
+    /// ignored on a 2D field. Panics unless `data.len() == rows·cols` with
+    /// both at least 1 and `plane_z` lies on the grid.
+    pub fn seed_from_grid(&mut self, data: &[f64], rows: usize, cols: usize, plane_z: f64, scale: f64) {
+        assert!(rows >= 1 && cols >= 1, "seed_from_grid requires at least one row and column");
+        assert_eq!(data.len(), rows * cols, "seed_from_grid data must hold rows × cols values");
+        let ((x0, x1), (y0, y1), _) = self.extents;
+        assert!(self.contains((x0, y0, plane_z)), "seed_from_grid plane z = {} lies outside the grid", plane_z);
+        let (_, _, k) = self.nearest_cell((x0, y0, plane_z));
+        
+        // Lower picture index and fraction toward the next along one axis
+        let locate = |u: f64, count: usize| {
+            let u = u.clamp(0.0, (count - 1) as f64);
+            let lower = (u.floor() as usize).min(count.saturating_sub(2));
+            (lower, u - lower as f64)
+        };
+        let pixel = |row: usize, col: usize| data[row.min(rows - 1) * cols + col.min(cols - 1)];
+        let (nx, ny, _) = self.shape;
+        let mut deposits = Vec::with_capacity(nx * ny);
+        for j in 0..ny {
+            for i in 0..nx {
+                let (x, y, z) = self.cell_position(i, j, k);
+                let (col, tx) = locate((x - x0) / (x1 - x0) * (cols - 1) as f64, cols);
+                let (row, ty) = locate((y1 - y) / (y1 - y0) * (rows - 1) as f64, rows);
+                let top = pixel(row, col) + (pixel(row, col + 1) - pixel(row, col)) * tx;
+                let bottom = pixel(row + 1, col) + (pixel(row + 1, col + 1) - pixel(row + 1, col)) * tx;
+                let value = scale * (top + (bottom - top) * ty);
+                if value != 0.0 {
+                    deposits.push(((x, y, z), value));
+                }
+            }
+        }
+        self.add_information_batch(&deposits).expect("plane cells lie on the grid");
+    }
+    
+    /// Cellwise mean field over an ensemble of seeded realizations
+    /// 
+    /// Builds `base` once per seed, evolves each `steps` steps and averages
+    /// the densities. Time and step count are those of the realizations.
+    /// 
+    /// Panics if `seeds` is empty.
+    pub fn ensemble_average(base: &RealityConfig, seeds: &[u64], steps: usize) -> Reality {
+        assert!(!seeds.is_empty(), "ensemble_average requires at least one seed");
+        
+        let mut sums = Vec::new();
+        let mut last = None;
+        for &seed in seeds {
+            let mut realization = base.build_seeded(seed);
+            for _ in 0..steps {
+                realization.evolve();
+            }
+            sums.resize(realization.field.len(), 0.0);
+            for (sum, info) in sums.iter_mut().zip(&realization.field) {
+                *sum += info.density();
+            }
+            last = Some(realization);
+        }
+        
+        let mut average = last.unwrap();
+        let count = seeds.len() as f64;
+        for (cell, sum) in average.field.iter_mut().zip(sums) {
+            *cell = Information(sum / count);
+        }
+        average.recount();
+        average
+    }
+    
+    /// Coarse-grained copy with (N-1)/factor + 1 cells per axis over the same bounds
+    /// 
+    /// Each coarse cell is the box average of the cells within half a coarse
+    /// spacing of it, so interior coarse cells average factor³ blocks
+    /// (factor² on a 2D field), cells midway between two coarse cells count
+    /// half towards each, and face cells average the half blocks against
+    /// the faces. Diffusion is rescaled to keep the physical coefficient
+    /// D·Δx²; time, step and settings carry over, while probes, history and
+    /// the op log do not. Panics unless factor ≥ 1 and the coarse grid keeps
+    /// at least two cells per axis.
+    pub fn downsample(&self, factor: usize) -> Reality {
+        assert!(factor >= 1, "downsample requires factor >= 1");
+        let coarse_count = |count: usize| if count == 1 { 1 } else { (count - 1) / factor + 1 };
+        let (nx, ny, nz) = self.shape;
+        let shape = (coarse_count(nx), coarse_count(ny), coarse_count(nz));
+        assert!(
+            shape.0 >= 2 && shape.1 >= 2 && (self.planar || shape.2 >= 2),
+            "downsample by {} leaves fewer than two cells on an axis", factor);
+        
+        // Box weights of a fine index towards its one or two nearest coarse cells
+        let weights = |i: usize, count: usize, coarse: usize| -> Vec<(usize, f64)> {
+            if coarse == 1 {
+                return vec![(0, 1.0)];
+            }
+            let ratio = (count - 1) as f64 / (coarse - 1) as f64;
+            let u = i as f64 / ratio;
+            let lower = (u.floor() as usize).min(coarse - 1);
+            let offset = u - lower as f64;
+            if (offset - 0.5).abs() < 1e-9 {
+                vec![(lower, 0.5), (lower + 1, 0.5)]
+            } else {
+                vec![(if offset < 0.5 { lower } else { lower + 1 }, 1.0)]
+            }
+        };
+        
+        let mut coarse = self.regridded(shape);
+        let mut sums = vec![(0.0, 0.0); coarse.field.len()];
+        for (i, j, k, _, density) in self.iter_cells() {
+            for (ci, wx) in weights(i, nx, shape.0) {
+                for (cj, wy) in weights(j, ny, shape.1) {
+                    for &(ck, wz) in &weights(k, nz, shape.2) {
+                        let weight = wx * wy * wz;
+                        let slot = &mut sums[coarse.index(ci, cj, ck)];
+                        *slot = (slot.0 + weight * density, slot.1 + weight);
+                    }
+                }
+            }
+        }
+        for (cell, (sum, weight)) in coarse.field.iter_mut().zip(sums) {
+            *cell = Information(sum / weight);
+        }
+        coarse.recount();
+        coarse
+    }
+    
+    /// Refined copy with (N-1)·factor + 1 cells per axis over the same bounds
+    /// 
+    /// Densities are trilinearly interpolated (bilinearly on a 2D field), so
+    /// the original cells are reproduced exactly and `downsample(factor)`
+    /// restores the original grid shape. Diffusion is rescaled to keep the
+    /// physical coefficient D·Δx², and dt shrinks if the finer grid would
+    /// otherwise exceed the stability limit. Time, step and settings carry
+    /// over; probes, history and the op log do not. Panics unless factor ≥ 1.
+    pub fn upsample(&self, factor: usize) -> Reality {
+        assert!(factor >= 1, "upsample requires factor >= 1");
+        let fine_count = |count: usize| (count - 1) * factor + 1;
+        let (nx, ny, nz) = self.shape;
+        let mut fine = self.regridded((fine_count(nx), fine_count(ny), fine_count(nz)));
+        for idx in 0..fine.field.len() {
+            let position = fine.index_position(idx);
+            fine.field[idx] = Information(self.information_at_interpolated(position).expect("fine cells lie inside the grid"));
+        }
+        fine.recount();
+        fine
+    }
+    
+    /// Evolve one time step: ∂ℐ/∂t = D∇²ℐ - ε²ℐ + ℐ(1-ℐ/ℐ_max)
+    /// 
+    /// With the `parallel` feature the rates and the update run on rayon,
+    /// reading only the previous field, so results match the sequential step.
+    pub fn evolve(&mut self) {
+        self.euler_update(self.dt);
+        self.time += self.dt;
+        self.step += 1;
+        self.log(Op::Evolve);
+        self.apply_pins();
+        self.apply_clamping();
+        self.sample_probes();
+        self.record_history();
+    }
+    
+    /// Integrate one step backward in time: ℐ ← ℐ - dt·∂ℐ/∂t
+    /// 
+    /// Forward Euler with -dt, negating both diffusion and the intrinsic
+    /// rate; `time` decreases by dt while `step` still counts the step. The
+    /// nonlinear rate makes it no exact inverse of `evolve`: a forward and
+    /// backward pair leaves an O(dt²) residue per cell, which is the
+    /// irreversibility it measures. Backward diffusion sharpens, so only
+    /// smooth fields survive more than a few steps.
+    pub fn evolve_backward(&mut self) {
+        self.euler_update(-self.dt);
+        self.time -= self.dt;
+        self.step += 1;
+        self.log(Op::EvolveBackward);
+        self.apply_pins();
+        self.apply_clamping();
+        self.sample_probes();
+        self.record_history();
+    }
+    
+    /// Relax toward another field: ℐ ← ℐ + dt·strength·(ℐ_other - ℐ) per cell
+    /// 
+    /// The coupling half of an operator-split step; call it once per `evolve`
+    /// to model entanglement with an environment. `other` is read, not
+    /// changed - for mutual coupling couple each side to the other's state
+    /// from before either coupling. Time is not advanced and the call is not
+    /// recorded. Panics if the grids differ in size.
+    pub fn couple(&mut self, other: &Reality, strength: f64) {
+        assert_eq!(self.field.len(), other.field.len(), "couple requires matching grids");
+        let rate = self.dt * strength;
+        for (idx, partner) in other.field.iter().enumerate() {
+            let density = self.field[idx].density();
+            let coupled = self.information(density + rate * (partner.density() - density));
+            self.set_cell(idx, coupled);
+        }
+    }
+    
+    /// Evolve one time step with classical 4th-order Runge-Kutta
+    /// 
+    /// Same master equation and stencil as `evolve`, with four rate
+    /// evaluations per step. Intermediate stages are not clamped; the final
+    /// density is, as in `evolve`. Faces stay fixed.
+    pub fn evolve_rk4(&mut self) {
+        let dt = self.dt;
+        let stage = |field: &[Information], slope: &[f64], h: f64| -> Vec<Information> {
+            field.iter().zip(slope).map(|(info, k)| Information(info.density() + h * k)).collect()
+        };
+        
+        let k1 = self.rates(&self.field);
+        let k2 = self.rates(&stage(&self.field, &k1, dt / 2.0));
+        let k3 = self.rates(&stage(&self.field, &k2, dt / 2.0));
+        let k4 = self.rates(&stage(&self.field, &k3, dt));
+        
+        for idx in 0..self.field.len() {
+            let change = (k1[idx] + 2.0 * k2[idx] + 2.0 * k3[idx] + k4[idx]) / 6.0;
+            if change != 0.0 {
+                let new_info = self.information(self.field[idx].density() + dt * change);
+                self.set_cell(idx, new_info);
+            }
+        }
+        
+        self.time += dt;
+        self.step += 1;
+        self.log(Op::EvolveRk4);
+        self.apply_pins();
+        self.apply_clamping();
+        self.sample_probes();
+        self.record_history();
+    }
+    
+    /// Evolve one time step with implicit Crank–Nicolson diffusion
+    /// 
+    /// Douglas ADI in increment form: the full explicit rate dt·∂ℐ/∂t is
+    /// corrected by one tridiagonal solve (I - ½dt·D∂²) per axis, so the
+    /// diffusion term is unconditionally stable and second order in time,
+    /// while the reaction terms stay explicit. dt is then limited by the
+    /// reaction (of order 1) rather than D·dt ≤ 1/6, letting stiff
+    /// high-diffusion runs take far larger steps: build at a stable dt and
+    /// raise it with `set_dt_unchecked`. Faces stay fixed and the result is clamped as
+    /// in `evolve`.
+    pub fn evolve_implicit(&mut self) {
+        let dt = self.dt;
+        let (nx, ny, nz) = self.shape;
+        let mut increment: Vec<f64> = self.rates(&self.field).into_iter().map(|rate| dt * rate).collect();
+        
+        let (d_x, d_y, d_z) = self.diffusion_axes();
+        let mut sweep = |coupling: f64, starts: Vec<usize>, stride: usize, length: usize| {
+            let mut line = vec![0.0; length];
+            for start in starts {
+                for (c, value) in line.iter_mut().enumerate() {
+                    *value = increment[start + c * stride];
+                }
+                solve_crank_nicolson_line(&mut line, coupling);
+                for (c, value) in line.iter().enumerate() {
+                    increment[start + c * stride] = *value;
+                }
+            }
+        };
+        let plane = nx * ny;
+        sweep(0.5 * dt * d_x, (0..nz).flat_map(|k| (0..ny).map(move |j| k * plane + j * nx)).collect(), 1, nx);
+        sweep(0.5 * dt * d_y, (0..nz).flat_map(|k| (0..nx).map(move |i| k * plane + i)).collect(), nx, ny);
+        if !self.planar {
+            sweep(0.5 * dt * d_z, (0..plane).collect(), plane, nz);
+        }
+        
+        for (idx, change) in increment.into_iter().enumerate() {
+            if change != 0.0 {
+                let new_info = self.information(self.field[idx].density() + change);
+                self.set_cell(idx, new_info);
+            }
+        }
+        
+        self.time += dt;
+        self.step += 1;
+        self.log(Op::EvolveImplicit);
+        self.apply_pins();
+        self.apply_clamping();
+        self.sample_probes();
+        self.record_history();
+    }
+    
+    /// Take one `evolve` step with dt adapted to keep the local error under `tol`
+    /// 
+    /// The error of the current dt is estimated by step doubling
+    /// (`local_error`); dt shrinks until it meets `tol` or reaches the minimum,
+    /// the step is taken, and dt grows (at most twofold) for the next call.
+    /// Changes go through `set_dt_unchecked`, so the field's dt - and a recording -
+    /// follow the adaptation. Returns the dt actually used.
+    pub fn evolve_adaptive(&mut self, tol: f64) -> f64 {
+        let (min_dt, max_dt) = self.dt_limits;
+        let max_dt = max_dt.min(self.max_stable_dt());
+        let mut dt = self.dt.clamp(min_dt, max_dt);
+        
+        let mut error = self.local_error(dt);
+        while error > tol && dt > min_dt {
+            dt = (dt * (0.9 * (tol / error).sqrt()).min(0.5)).max(min_dt);
+            error = self.local_error(dt);
+        }
+        
+        // max_dt already holds the CFL limit, up to rounding in max_stable_dt
+        self.set_dt_unchecked(dt);
+        self.evolve();
+        
+        // Forward Euler local error scales as dt²
+        let growth = if error > 0.0 { (0.9 * (tol / error).sqrt()).min(2.0) } else { 2.0 };
+        self.set_dt_unchecked((dt * growth).clamp(min_dt, max_dt));
+        dt
+    }
+    
+    /// Solve the pure-diffusion steady state ∇²ℐ = 0 directly
+    /// 
+    /// Gauss–Seidel relaxation of the harmonic field with the given cells held
+    /// at fixed (Dirichlet) values. Domain faces that are not fixed are treated
+    /// as zero-flux, so fixing two opposite faces yields a linear ramp. This
+    /// differs from `evolve`, which holds every face at its current value: to
+    /// get that steady state, pass the face cells in `fixed`.
+    /// Per-axis coefficients from `set_diffusion_xyz` weight each axis.
+    /// The reaction terms are ignored; time and step count are unchanged.
+    /// Positions outside the grid are ignored.
+    /// 
+    /// Returns the number of sweeps once no cell moves by 1e-12, or `None`
+    /// if 100 000 sweeps did not get there (the field is left as relaxed).
+    pub fn solve_steady_diffusion(&mut self, fixed: &[((f64, f64, f64), f64)]) -> Option<usize> {
+        const TOLERANCE: f64 = 1e-12;
+        const MAX_SWEEPS: usize = 100_000;
+        
+        let (nx, ny, nz) = self.shape;
+        let plane = nx * ny;
+        let (w_x, w_y, w_z) = spacing_weights(self.shape, self.extents);
+        // Only the ratios of the per-axis coefficients shape the steady state
+        let (d_x, d_y, d_z) = self.diffusion_xyz.unwrap_or((1.0, 1.0, 1.0));
+        let (w_x, w_y, w_z) = (w_x * d_x, w_y * d_y, w_z * d_z);
+        let mut pinned = vec![false; self.field.len()];
+        for &(position, value) in fixed {
+            if let Ok(idx) = self.position_to_index(position) {
+                self.set_cell(idx, self.information(value));
+                pinned[idx] = true;
+            }
+        }
+        
+        for sweep in 1..=MAX_SWEEPS {
+            let mut max_update: f64 = 0.0;
+            for k in 0..nz {
+                for j in 0..ny {
+                    for i in 0..nx {
+                        let idx = self.index(i, j, k);
+                        if pinned[idx] {
+                            continue;
+                        }
+                        
+                        // Faces without a neighbour are zero-flux: average over existing
+                        // neighbours, weighted by Dₐ(Δx/Δxₐ)²
+                        let neighbors = [
+                            (i > 0).then(|| (idx - 1, w_x)),
+                            (i + 1 < nx).then(|| (idx + 1, w_x)),
+                            (j > 0).then(|| (idx - nx, w_y)),
+                            (j + 1 < ny).then(|| (idx + nx, w_y)),
+                            (k > 0).then(|| (idx - plane, w_z)),
+                            (k + 1 < nz).then(|| (idx + plane, w_z)),
+                        ];
+                        let (sum, weight) = neighbors.iter()
+                            .flatten()
+                            .fold((0.0, 0.0), |(sum, total), &(other, w)| (sum + w * self.field[other].density(), total + w));
+                        if weight == 0.0 {
+                            continue;
+                        }
+                        let center = self.field[idx].density();
+                        let updated = sum / weight;
+                        
+                        max_update = max_update.max((updated - center).abs());
+                        self.set_cell(idx, self.information(updated));
+                    }
+                }
+            }
+            if max_update < TOLERANCE {
+                return Some(sweep);
+            }
+        }
+        None
+    }
+    
+    /// Measure the travelling-front speed of the self-amplification frontier
+    /// 
+    /// Evolves `steps` steps while tracking where density crosses the midpoint
+    /// between vacuum and saturation along `axis`, on the line through the
+    /// domain centre. The outermost crossing in the positive direction is the
+    /// front. Returns its velocity (physical units per time) as a least-squares
+    /// fit over the second half of the run, after the initial transient.
+    /// 
+    /// Fisher–KPP theory predicts 2√(D·r)·Δx, with D in grid units and r the
+    /// linear growth rate ahead of the front.
+    /// 
+    /// Panics for `Axis::Z` on a 2D field.
+    pub fn front_speed(&mut self, axis: Axis, steps: usize) -> f64 {
+        assert!(!(self.planar && axis == Axis::Z), "a 2D field has no z extent");
+        let level = 0.5 * (self.vacuum_density() + saturation_density(self.max_information));
+        let (nx, ny, nz) = self.shape;
+        let (n, spacing) = self.axis_grid(axis);
+        let min_bound = match axis {
+            Axis::X => self.extents.0 .0,
+            Axis::Y => self.extents.1 .0,
+            Axis::Z => self.extents.2 .0,
+        };
+        
+        let line_index = |a: usize| match axis {
+            Axis::X => (a, ny / 2, nz / 2),
+            Axis::Y => (nx / 2, a, nz / 2),
+            Axis::Z => (nx / 2, ny / 2, a),
+        };
+        
+        let mut samples = Vec::with_capacity(steps);
+        for _ in 0..steps {
+            self.evolve();
+            
+            let density = |a: usize| {
+                let (i, j, k) = line_index(a);
+                self.field[self.index(i, j, k)].density()
+            };
+            if let Some(a) = (0..n - 1).rev().find(|&a| density(a) >= level && density(a + 1) < level) {
+                let fraction = (density(a) - level) / (density(a) - density(a + 1));
+                samples.push((self.time, min_bound + (a as f64 + fraction) * spacing));
+            }
+        }
+        
+        let fitted = &samples[samples.len() / 2..];
+        if fitted.len() < 2 {
+            return 0.0;
+        }
+        let count = fitted.len() as f64;
+        let mean_t = fitted.iter().map(|(t, _)| t).sum::<f64>() / count;
+        let mean_x = fitted.iter().map(|(_, x)| x).sum::<f64>() / count;
+        let covariance: f64 = fitted.iter().map(|(t, x)| (t - mean_t) * (x - mean_x)).sum();
+        let variance: f64 = fitted.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+        if variance == 0.0 { 0.0 } else { covariance / variance }
+    }
+    
+    /// Normalized cross-correlation of the density fluctuations in two regions
+    /// 
+    /// Each `(min, max)` box's densities minus its own mean are compared cell
+    /// by cell, cells matched by their offset from the box corner: 1 for the
+    /// same fluctuation pattern, -1 for an inverted one, near 0 for unrelated
+    /// ones, and 0 when either region is uniform. Unlike a low variance, it
+    /// measures shared structure. Panics unless both boxes hold cells and
+    /// span the same number of them along every axis.
+    pub fn coherence(&self, region_a: Region, region_b: Region) -> f64 {
+        // Fluctuations of a box in row-major order and its cells per axis
+        let fluctuations = |(min, max): Region| {
+            let cells: Vec<usize> = (0..self.field.len())
+                .filter(|&idx| self.box_contains(min, max, self.index_position(idx)))
+                .collect();
+            assert!(!cells.is_empty(), "coherence region {:?} holds no cells", (min, max));
+            let (first, last) = (self.cell_indices(cells[0]), self.cell_indices(cells[cells.len() - 1]));
+            let span = (last.0 - first.0 + 1, last.1 - first.1 + 1, last.2 - first.2 + 1);
+            let mean = cells.iter().map(|&idx| self.field[idx].density()).sum::<f64>() / cells.len() as f64;
+            (cells.iter().map(|&idx| self.field[idx].density() - mean).collect::<Vec<f64>>(), span)
+        };
+        let (a, span_a) = fluctuations(region_a);
+        let (b, span_b) = fluctuations(region_b);
+        assert_eq!(span_a, span_b, "coherence regions must span the same cells per axis");
+        
+        let covariance: f64 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+        let variance_a: f64 = a.iter().map(|a| a * a).sum();
+        let variance_b: f64 = b.iter().map(|b| b * b).sum();
+        if variance_a == 0.0 || variance_b == 0.0 {
+            0.0
+        } else {
+            covariance / (variance_a * variance_b).sqrt()
+        }
+    }
+    
+    /// Report which regions have equilibrated
+    /// 
+    /// Evolves one step on a scratch copy and flags each `(min, max)` box whose
+    /// largest per-cell density change is below `tol`. The field itself is untouched.
+    /// Panics if a box holds no cells.
+    pub fn region_equilibration(&self, regions: &[Region], tol: f64) -> Vec<bool> {
+        let mut scratch = self.scratch();
+        scratch.evolve();
+        
+        regions.iter().map(|&(min, max)| {
+            let changes: Vec<f64> = (0..self.field.len())
+                .filter(|&idx| self.box_contains(min, max, self.index_position(idx)))
+                .map(|idx| (scratch.field[idx].density() - self.field[idx].density()).abs())
+                .collect();
+            assert!(!changes.is_empty(), "equilibration region {:?} holds no cells", (min, max));
+            changes.into_iter().fold(0.0, f64::max) < tol
+        }).collect()
+    }
+    
+    /// Run up to `steps` `evolve` steps, calling `callback` after each
+    /// 
+    /// The callback sees the field and the 0-based index of the step just
+    /// taken; returning `ControlFlow::Break(())` stops early. Returns the
+    /// number of steps taken.
+    pub fn evolve_with<F>(&mut self, steps: usize, mut callback: F) -> usize
+    where
+        F: FnMut(&Reality, usize) -> ControlFlow<()>,
+    {
+        for index in 0..steps {
+            self.evolve();
+            if callback(self, index).is_break() {
+                return index + 1;
+            }
+        }
+        steps
+    }
+    
+    /// Evolve until no cell changes by `tol` or more in one step
+    /// 
+    /// Stops after the first step whose largest per-cell change
+    /// (`StepReport::max_change`) is below `tol`, or after `max_steps`.
+    /// Returns the number of steps taken; `max_steps` means not yet steady.
+    pub fn evolve_until_steady(&mut self, tol: f64, max_steps: usize) -> usize {
+        for taken in 1..=max_steps {
+            self.evolve();
+            if self.last_residual() < tol {
+                return taken;
+            }
+        }
+        max_steps
+    }
+    
+    /// Local rate ℐ(1-ℐ/ℐ_max) - ε²ℐ at position, ignoring diffusion
+    /// 
+    /// The instantaneous source minus uncertainty sink with this field's
+    /// ℐ_max and ε: positive where the cell is self-amplifying, negative
+    /// where it decays. `None` where `contains` is false.
+    pub fn net_local_rate(&self, position: (f64, f64, f64)) -> Option<f64> {
+        self.position_to_index(position).ok().map(|idx| self.intrinsic_rate(self.field[idx]))
+    }
+    
+    /// Steps of `evolve` until the cell at `position` becomes conscious
+    /// 
+    /// Returns `Some(n)` once ℐ ≥ ℐ_crit after the n-th step (`Some(0)`
+    /// without evolving if it already is), or `None` if it is still below
+    /// the threshold after `max_steps`. Panics if `position` is off the grid.
+    pub fn steps_to_conscious(&mut self, position: (f64, f64, f64), max_steps: usize) -> Option<usize> {
+        let idx = self.position_to_index(position)
+            .unwrap_or_else(|()| panic!("steps_to_conscious position {:?} lies outside the grid", position));
+        for taken in 0..=max_steps {
+            if self.field[idx].is_conscious() {
+                return Some(taken);
+            }
+            if taken < max_steps {
+                self.evolve();
+            }
+        }
+        None
+    }
+    
+    /// `evolve`, then fail if any cell diverged
+    /// 
+    /// A cell that is NaN, infinite or above `DIVERGENCE_FACTOR`·ℐ_max
+    /// after the step yields `EvolveError::Diverged`, so automated sweeps
+    /// can abort a bad parameter set early. The step is taken either way.
+    /// With `set_clamping` on the field cannot diverge.
+    pub fn evolve_checked(&mut self) -> Result<(), EvolveError> {
+        self.evolve();
+        let limit = DIVERGENCE_FACTOR * self.max_information;
+        match self.field.iter().position(|info| !(info.density().is_finite() && info.density() <= limit)) {
+            Some(idx) => Err(EvolveError::Diverged { position: self.index_position(idx), density: self.field[idx].density() }),
+            None => Ok(()),
+        }
+    }
+    
+    /// Evolve one time step and report where the field changed most
+    /// 
+    /// Pinpoints the cell driving a stiff or diverging run.
+    pub fn evolve_reporting(&mut self) -> StepReport {
+        let previous = self.field.clone();
+        self.evolve();
+        
+        let (idx, max_change) = self.field.iter()
+            .zip(&previous)
+            .map(|(new, old)| (new.density() - old.density()).abs())
+            .enumerate()
+            .fold((0, 0.0), |best, (idx, change)| if change > best.1 { (idx, change) } else { best });
+        
+        StepReport {
+            max_change,
+            at: self.index_position(idx),
+            total_information: self.total_information(),
+        }
+    }
+    
+    /// Information-weighted centroid: Σℐr / Σℐ
+    pub fn center_of_information(&self) -> (f64, f64, f64) {
+        let mut weighted = (0.0, 0.0, 0.0);
+        for (idx, info) in self.field.iter().enumerate() {
+            let (x, y, z) = self.index_position(idx);
+            let density = info.density();
+            weighted.0 += density * x;
+            weighted.1 += density * y;
+            weighted.2 += density * z;
+        }
+        let total = self.total_information();
+        (weighted.0 / total, weighted.1 / total, weighted.2 / total)
+    }
+    
+    /// Information inside a sphere: Σℐ·ΔV over cells whose centers lie within
+    /// `radius` of `center` (ΔV = Δx³, or Δx² on a 2D field where z is ignored)
+    pub fn information_in_sphere(&self, center: (f64, f64, f64), radius: f64) -> f64 {
+        self.information_where(|(x, y, z)| {
+            let dz = if self.planar { 0.0 } else { z - center.2 };
+            (x - center.0).powi(2) + (y - center.1).powi(2) + dz * dz <= radius * radius
+        })
+    }
+    
+    /// RMS gradient component along each axis over the whole grid: √⟨(∂ℐ/∂xᵢ)²⟩
+    /// 
+    /// Same gradients as `gradient`. Comparable components mean isotropic
+    /// structure; one dominating component signals organized directional
+    /// flow. z is 0 on a 2D field.
+    pub fn gradient_anisotropy(&self) -> (f64, f64, f64) {
+        let mut sums = (0.0, 0.0, 0.0);
+        for idx in 0..self.field.len() {
+            let (i, j, k) = self.cell_indices(idx);
+            let (gx, gy, gz) = self.cell_gradient(i, j, k);
+            sums.0 += gx * gx;
+            sums.1 += gy * gy;
+            sums.2 += gz * gz;
+        }
+        let count = self.field.len() as f64;
+        ((sums.0 / count).sqrt(), (sums.1 / count).sqrt(), (sums.2 / count).sqrt())
+    }
+    
+    /// Local stability 1 - Var(ℐ)/⟨ℐ⟩² over the cells within `radius` of `center`
+    /// 
+    /// 1 for a uniform neighbourhood, falling toward 0 as the relative spread
+    /// grows; clamped at 0. A neighbourhood with no cells or zero mean
+    /// counts as uniform. z is ignored on a 2D field.
+    pub fn local_stability(&self, center: (f64, f64, f64), radius: f64) -> f64 {
+        let (mut count, mut sum, mut sum_sq) = (0.0, 0.0, 0.0);
+        for (idx, info) in self.field.iter().enumerate() {
+            let (x, y, z) = self.index_position(idx);
+            let dz = if self.planar { 0.0 } else { z - center.2 };
+            if (x - center.0).powi(2) + (y - center.1).powi(2) + dz * dz <= radius * radius {
+                count += 1.0;
+                sum += info.density();
+                sum_sq += info.density().powi(2);
+            }
+        }
+        if count == 0.0 || sum == 0.0 {
+            return 1.0;
+        }
+        let mean = sum / count;
+        let variance = (sum_sq / count - mean * mean).max(0.0);
+        (1.0 - variance / (mean * mean)).max(0.0)
+    }
+    
+    /// Information inside the box with corners `min` and `max`: Σℐ·ΔV over
+    /// enclosed cell centers (z is ignored on a 2D field)
+    pub fn information_in_box(&self, min: (f64, f64, f64), max: (f64, f64, f64)) -> f64 {
+        self.information_where(|position| self.box_contains(min, max, position))
+    }
+    
+    /// Spatial peaks: cells above `min_level` strictly denser than all their
+    /// neighbours
+    /// 
+    /// Neighbours are the 26 surrounding cells (8 on a 2D field); face cells
+    /// are compared with the neighbours inside the grid. A flat plateau has no
+    /// peak.
+    pub fn local_maxima(&self, min_level: f64) -> Vec<((f64, f64, f64), f64)> {
+        let (nx, ny, nz) = self.shape;
+        let around = |c: usize, len: usize| c.saturating_sub(1)..=(c + 1).min(len - 1);
+        let mut peaks = Vec::new();
+        for k in 0..nz {
+            for j in 0..ny {
+                for i in 0..nx {
+                    let density = self.field[self.index(i, j, k)].density();
+                    if density <= min_level {
+                        continue;
+                    }
+                    let is_peak = around(k, nz).all(|nk| around(j, ny).all(|nj| around(i, nx).all(|ni| {
+                        (ni, nj, nk) == (i, j, k) || self.field[self.index(ni, nj, nk)].density() < density
+                    })));
+                    if is_peak {
+                        peaks.push((self.cell_position(i, j, k), density));
+                    }
+                }
+            }
+        }
+        peaks
+    }
+    
+    /// Shannon entropy of the excess over vacuum: -Σ p ln p (nats)
+    /// 
+    /// p is each cell's share of the total excess max(ℐ - ℐ_vac, 0). Ranges
+    /// from 0 for a single spike to ln(cell count) for uniform excess; a field
+    /// with no excess has zero entropy.
+    pub fn shannon_entropy(&self) -> f64 {
+        let vacuum = self.vacuum_density();
+        let excess = |info: &Information| (info.density() - vacuum).max(0.0);
+        let total: f64 = self.field.iter().map(excess).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        -self.field.iter()
+            .map(|info| excess(info) / total)
+            .filter(|&p| p > 0.0)
+            .map(|p| p * p.ln())
+            .sum::<f64>()
+    }
+    
+    /// `shannon_entropy` in bits (log₂)
+    pub fn spatial_entropy_bits(&self) -> f64 {
+        self.shannon_entropy() / std::f64::consts::LN_2
+    }
+    
+    /// Source, sink and boundary flux of the last `evolve` or `evolve_backward`
+    /// 
+    /// d(total)/dt = source - sink - boundary flux holds per step: the
+    /// interior Laplacian sums to the exchange with the fixed faces. Zero
+    /// before the first step; other integrators leave it unchanged.
+    pub fn information_balance(&self) -> InfoBalance {
+        self.last_balance
+    }
+    
+    /// Largest absolute per-cell density change of the last `evolve` or
+    /// `evolve_backward`
+    /// 
+    /// Tracked during the update, so it costs nothing; `StepReport` adds
+    /// where it happened. Zero before the first step; other integrators
+    /// leave it unchanged.
+    pub fn last_residual(&self) -> f64 {
+        self.last_residual
+    }
+    
+    /// Position and density of the densest cell
+    /// 
+    /// Ties go to the first cell in x-fastest order. `None` only for an
+    /// empty field.
+    pub fn argmax_consciousness(&self) -> Option<((f64, f64, f64), f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        for (idx, info) in self.field.iter().enumerate() {
+            if best.is_none_or(|(_, density)| info.density() > density) {
+                best = Some((idx, info.density()));
+            }
+        }
+        best.map(|(idx, density)| (self.index_position(idx), density))
+    }
+    
+    /// Information-weighted moment of inertia about an axis: Σℐ·r⊥²
+    /// 
+    /// `r⊥` is each cell's perpendicular distance from the line through
+    /// `center` along `axis` (need not be normalized). Combined with
+    /// `center_of_information`, measures how spread a structure is about an axis.
+    pub fn moment_of_inertia(&self, center: (f64, f64, f64), axis: (f64, f64, f64)) -> f64 {
+        let norm = (axis.0 * axis.0 + axis.1 * axis.1 + axis.2 * axis.2).sqrt();
+        let (ax, ay, az) = (axis.0 / norm, axis.1 / norm, axis.2 / norm);
+        
+        self.field.iter().enumerate().map(|(idx, info)| {
+            let (x, y, z) = self.index_position(idx);
+            let (dx, dy, dz) = (x - center.0, y - center.1, z - center.2);
+            let along = dx * ax + dy * ay + dz * az;
+            let perpendicular_sq = (dx * dx + dy * dy + dz * dz - along * along).max(0.0);
+            info.density() * perpendicular_sq
+        }).sum()
+    }
+    
+    /// Classify every cell by its amplification over a short scratch run
+    /// 
+    /// Evolves a copy for `steps` steps and labels each cell from
+    /// ℐ_final/ℐ_initial. Returned in `as_slice` ordering; the field itself is untouched.
+    pub fn classify_cells(&self, steps: usize) -> Vec<CellClass> {
+        let mut scratch = self.scratch();
+        for _ in 0..steps {
+            scratch.evolve();
+        }
+        
+        self.field.iter().zip(&scratch.field).map(|(initial, evolved)| {
+            let ratio = if initial.density() > 0.0 {
+                evolved.density() / initial.density()
+            } else if evolved.density() > 0.0 {
+                f64::INFINITY
+            } else {
+                1.0
+            };
+            CellClass::from_amplification(ratio)
+        }).collect()
+    }
+    
+    /// Every cell as (i, j, k, position, density), x-fastest
+    pub fn iter_cells(&self) -> impl Iterator<Item = (usize, usize, usize, (f64, f64, f64), f64)> + '_ {
+        self.field.iter().enumerate().map(move |(idx, info)| {
+            let (i, j, k) = self.cell_indices(idx);
+            (i, j, k, self.cell_position(i, j, k), info.density())
+        })
+    }
+    
+    /// Conscious cells as (x, y, z, density), x-fastest, without allocating
+    /// 
+    /// The order is guaranteed row-major by grid index (k, j, i): z slowest,
+    /// then y, then x, independent of how the cells became conscious.
+    pub fn conscious_points_iter(&self) -> impl Iterator<Item = (f64, f64, f64, f64)> + '_ {
+        self.field.iter().enumerate()
+            .filter(|(_, info)| info.is_conscious())
+            .map(move |(idx, info)| {
+                let (x, y, z) = self.index_position(idx);
+                (x, y, z, info.density())
+            })
+    }
+    
+    /// Every cell as (i, j, k, position, &mut Information), x-fastest
+    /// 
+    /// For in-place edits such as custom perturbations. Assign through
+    /// `Information::new` to keep densities clamped. The running totals are
+    /// rebuilt on the next mutation; queries scan the field until then.
+    pub fn iter_cells_mut(&mut self) -> impl Iterator<Item = (usize, usize, usize, (f64, f64, f64), &mut Information)> + '_ {
+        self.totals_stale = true;
+        let (nx, ny, _) = self.shape;
+        let planar = self.planar;
+        let ((x0, _), (y0, _), (z0, _)) = self.extents;
+        let (dx, dy, dz) = self.spacing();
+        self.field.iter_mut().enumerate().map(move |(idx, info)| {
+            let (i, j, k) = (idx % nx, (idx / nx) % ny, idx / (nx * ny));
+            let position = (
+                x0 + i as f64 * dx,
+                y0 + j as f64 * dy,
+                if planar { 0.0 } else { z0 + k as f64 * dz },
+            );
+            (i, j, k, position, info)
+        })
+    }
+    
+    /// Raw field cells, x-fastest (index = k·Nx·Ny + j·Nx + i)
+    pub fn as_slice(&self) -> &[Information] {
+        &self.field
+    }
+    
+    /// Cell-centre point cloud of the iso-surface at `level`
+    /// 
+    /// Returns every cell at or above `level` with at least one face neighbour
+    /// below it - the inner skin of the region. Suitable for point-cloud
+    /// rendering or as input to an external marching-cubes mesher.
+    pub fn consciousness_isosurface_points(&self, level: f64) -> Vec<(f64, f64, f64)> {
+        let (nx, ny, nz) = self.shape;
+        let plane = nx * ny;
+        let mut points = Vec::new();
+        for k in 0..nz {
+            for j in 0..ny {
+                for i in 0..nx {
+                    let idx = self.index(i, j, k);
+                    if self.field[idx].density() < level {
+                        continue;
+                    }
+                    let neighbors = [
+                        (i > 0).then(|| idx - 1),
+                        (i + 1 < nx).then(|| idx + 1),
+                        (j > 0).then(|| idx - nx),
+                        (j + 1 < ny).then(|| idx + nx),
+                        (k > 0).then(|| idx - plane),
+                        (k + 1 < nz).then(|| idx + plane),
+                    ];
+                    if neighbors.iter().flatten().any(|&other| self.field[other].density() < level) {
+                        points.push(self.cell_position(i, j, k));
+                    }
+                }
+            }
+        }
+        points
+    }
+    
+    /// Point cloud of the consciousness boundary (iso-level `INTEGRATION_THRESHOLD`)
+    pub fn consciousness_boundary_points(&self) -> Vec<(f64, f64, f64)> {
+        self.consciousness_isosurface_points(INTEGRATION_THRESHOLD)
+    }
+    
+    /// Diffusion stability number D·dt/Δx²
+    /// 
+    /// The evolution stencil works in grid units, so `diffusion` is already
+    /// D/Δx² and this is `diffusion·dt`. The explicit step is stable up to
+    /// `MAX_STABILITY_NUMBER` (`MAX_STABILITY_NUMBER_2D` on a 2D field);
+    /// `set_dt` enforces it; `set_dt_unchecked` and `set_diffusion` do not.
+    /// With per-axis diffusion the mean coefficient takes D's place; on a
+    /// `new_rect` grid D is scaled by the mean (Δx/Δxₐ)² weight.
+    pub fn stability_number(&self) -> f64 {
+        self.effective_diffusion() * self.dt
+    }
+    
+    /// Richardson estimate of the local error of one step of size `dt`
+    /// 
+    /// Compares one step of `dt` against two of `dt/2` on scratch copies.
+    /// Forward Euler is first order, so the full step's error is about twice
+    /// their maximum per-cell difference.
+    pub fn local_error(&self, dt: f64) -> f64 {
+        let mut coarse = self.scratch();
+        coarse.dt = dt;
+        coarse.evolve();
+        
+        let mut fine = self.scratch();
+        fine.dt = dt / 2.0;
+        fine.evolve();
+        fine.evolve();
+        
+        2.0 * coarse.field.iter()
+            .zip(&fine.field)
+            .map(|(a, b)| (a.density() - b.density()).abs())
+            .fold(0.0, f64::max)
+    }
+    
+    /// Largest dt (within the CFL limit) keeping the per-step error under `target_error`
+    /// 
+    /// Local error of forward Euler scales as dt², so the Richardson estimate
+    /// is rescaled by √(target/error) and refined a few times. Configure the
+    /// result with `set_dt` before a run.
+    pub fn suggest_dt(&self, target_error: f64) -> f64 {
+        let limit = self.max_stable_dt();
+        let mut dt = self.dt.min(limit);
+        for _ in 0..6 {
+            let error = self.local_error(dt);
+            if error == 0.0 {
+                return limit;
+            }
+            dt = (dt * (target_error / error).sqrt()).min(limit);
+        }
+        dt
+    }
+    
+    /// Get information at position
+    pub fn information_at(&self, position: (f64, f64, f64)) -> Option<Information> {
+        self.information_at_checked(position).ok()
+    }
+    
+    /// Density at position, trilinearly interpolated among the 8 surrounding cells
+    /// 
+    /// Unlike `information_at`, which snaps to the nearest cell, this varies
+    /// smoothly between cell centers and reproduces them exactly, so radial
+    /// and line scans come out without staircase steps. Bilinear on a 2D
+    /// field, where z is ignored. `None` outside the grid extents.
+    pub fn information_at_interpolated(&self, (x, y, z): (f64, f64, f64)) -> Option<f64> {
+        // Lower cell index and fraction toward the next cell along one axis
+        let locate = |c: f64, axis: Axis, (min_bound, max_bound): (f64, f64)| {
+            let (count, spacing) = self.axis_grid(axis);
+            if count == 1 {
+                return Some((0, 0.0));
+            }
+            if !(min_bound..=max_bound).contains(&c) {
+                return None;
+            }
+            let u = ((c - min_bound) / spacing).clamp(0.0, (count - 1) as f64);
+            let lower = (u.floor() as usize).min(count - 2);
+            Some((lower, u - lower as f64))
+        };
+        let (i, tx) = locate(x, Axis::X, self.extents.0)?;
+        let (j, ty) = locate(y, Axis::Y, self.extents.1)?;
+        let (k, tz) = if self.planar { (0, 0.0) } else { locate(z, Axis::Z, self.extents.2)? };
+        
+        let corner = |di: usize, dj: usize, dk: usize| {
+            let k = if self.planar { 0 } else { k + dk };
+            self.field[self.index(i + di, j + dj, k)].density()
+        };
+        let lerp = |a: f64, b: f64, t: f64| a + (b - a) * t;
+        let plane = |dk: usize| {
+            lerp(lerp(corner(0, 0, dk), corner(1, 0, dk), tx), lerp(corner(0, 1, dk), corner(1, 1, dk), tx), ty)
+        };
+        Some(if self.planar { plane(0) } else { lerp(plane(0), plane(1), tz) })
+    }
+    
+    /// Information at position, or an `OutOfBounds` error naming the
+    /// position and the valid bounds
+    /// 
+    /// Succeeds exactly when `contains` is true.
+    pub fn information_at_checked(&self, position: (f64, f64, f64)) -> Result<Information, OutOfBounds> {
+        self.position_to_index(position)
+            .map(|idx| self.field[idx])
+            .map_err(|()| OutOfBounds { position, bounds: self.extents })
+    }
+
+    /// Whether position maps onto a grid cell
+    /// 
+    /// True within half a cell of `bounds` - exactly when `information_at`
+    /// returns `Some`. z is ignored on a 2D field.
+    pub fn contains(&self, position: (f64, f64, f64)) -> bool {
+        self.position_to_index(position).is_ok()
+    }
+    
+    /// Grid indices (i, j, k) of the cell nearest to position
+    /// 
+    /// Positions outside the grid are clamped onto its faces; k is 0 on a
+    /// 2D field.
+    pub fn nearest_cell(&self, (x, y, z): (f64, f64, f64)) -> (usize, usize, usize) {
+        (self.axis_index(Axis::X, x), self.axis_index(Axis::Y, y), self.axis_index(Axis::Z, z))
+    }
+
+    /// Gradient ∇ℐ at position
+    /// 
+    /// Central differences over the real grid spacing, falling back to
+    /// one-sided differences on the domain faces. On a 2D field z is ignored
+    /// and the z component is zero.
+    /// Returns `None` where `contains` is false.
+    pub fn gradient(&self, position: (f64, f64, f64)) -> Option<(f64, f64, f64)> {
+        let (i, j, k) = self.position_to_cell(position)?;
+        Some(self.cell_gradient(i, j, k))
+    }
+    
+    /// Divergence ∇·v of the information flow velocity v = -D∇ℐ
+    /// 
+    /// D is the physical diffusion coefficient `diffusion·Δx²`, so v is the
+    /// diffusive flux of `evolve` and -∇·v is its diffusion term at the cell.
+    /// Returns `None` where `contains` is false.
+    pub fn divergence(&self, position: (f64, f64, f64)) -> Option<f64> {
+        let (i, j, k) = self.position_to_cell(position)?;
+        Some(
+            self.axis_derivative(Axis::X, i, &|c| self.flow_velocity(c, j, k).0)
+                + self.axis_derivative(Axis::Y, j, &|c| self.flow_velocity(i, c, k).1)
+                + self.axis_derivative(Axis::Z, k, &|c| self.flow_velocity(i, j, c).2),
+        )
+    }
+    
+    /// Vorticity ∇×v of the information flow velocity v = -D∇ℐ
+    /// 
+    /// Same convention as `divergence`. A pure gradient flow is irrotational,
+    /// so away from the faces this is zero up to rounding; non-zero values flag
+    /// discretisation error or boundary effects rather than physical swirl.
+    /// Returns `None` where `contains` is false.
+    pub fn vorticity(&self, position: (f64, f64, f64)) -> Option<(f64, f64, f64)> {
+        let (i, j, k) = self.position_to_cell(position)?;
+        let d_dx = |component: fn((f64, f64, f64)) -> f64| self.axis_derivative(Axis::X, i, &|c| component(self.flow_velocity(c, j, k)));
+        let d_dy = |component: fn((f64, f64, f64)) -> f64| self.axis_derivative(Axis::Y, j, &|c| component(self.flow_velocity(i, c, k)));
+        let d_dz = |component: fn((f64, f64, f64)) -> f64| self.axis_derivative(Axis::Z, k, &|c| component(self.flow_velocity(i, j, c)));
+        
+        Some((
+            d_dy(|v| v.2) - d_dz(|v| v.1),
+            d_dz(|v| v.0) - d_dx(|v| v.2),
+            d_dx(|v| v.1) - d_dy(|v| v.0),
+        ))
+    }
+    
+    /// Information current J = -D∇ℐ at every cell, x-fastest like `as_slice`
+    /// 
+    /// Same flux as `divergence` and `vorticity` use, as (position, J) pairs.
+    pub fn current_field(&self) -> Vec<CurrentSample> {
+        (0..self.field.len()).map(|idx| {
+            let (i, j, k) = self.cell_indices(idx);
+            (self.cell_position(i, j, k), self.flow_velocity(i, j, k))
+        }).collect()
+    }
+    
+    /// Reynolds number Re = U·L/ν with U the RMS flow speed |J| over the domain
+    /// 
+    /// J is the current of `current_field`, so U reflects the whole flow
+    /// rather than one sampled point. `length_scale` is L in physical units and
+    /// `viscosity` the kinematic viscosity ν. Panics unless `viscosity` is
+    /// positive.
+    pub fn reynolds_number(&self, length_scale: f64, viscosity: f64) -> f64 {
+        assert!(viscosity > 0.0, "reynolds_number requires a positive viscosity");
+        let mean_square = self.current_field().iter()
+            .map(|&(_, (jx, jy, jz))| jx * jx + jy * jy + jz * jz)
+            .sum::<f64>() / self.field.len() as f64;
+        mean_square.sqrt() * length_scale / viscosity
+    }
+    
+    /// Pair binding energy ℐ₁ℐ₂/(r + r₀) between two positions, in bits²/unit
+    /// 
+    /// r is their separation and r₀ is `BINDING_SOFTENING_LENGTH`. Callers
+    /// apply their own unit scaling. Returns `None` if either position lies
+    /// off the grid.
+    pub fn binding_energy(&self, position1: (f64, f64, f64), position2: (f64, f64, f64)) -> Option<f64> {
+        let info1 = self.information_at(position1)?.density();
+        let info2 = self.information_at(position2)?.density();
+        let distance = ((position1.0 - position2.0).powi(2)
+            + (position1.1 - position2.1).powi(2)
+            + (position1.2 - position2.2).powi(2)).sqrt();
+        Some(info1 * info2 / (distance + BINDING_SOFTENING_LENGTH))
+    }
+    
+    /// Wavefront radius around `center`: where density last exceeds `threshold_factor`·ℐ_vac
+    /// 
+    /// Marches outward along the 26 lattice directions (8 in the plane on a
+    /// 2D field) to the edge of the grid, in steps of the finest grid spacing
+    /// so no cell is skipped on a `new_rect` grid. Takes the farthest sample
+    /// above threshold on each ray (0 if none) and averages over rays.
+    pub fn wavefront_radius(&self, center: (f64, f64, f64), threshold_factor: f64) -> f64 {
+        let threshold = threshold_factor * self.vacuum_density();
+        let (dx, dy, dz) = self.spacing();
+        let step_length = if self.planar { dx.min(dy) } else { dx.min(dy).min(dz) };
+        let z_steps: &[f64] = if self.planar { &[0.0] } else { &[-1.0, 0.0, 1.0] };
+        
+        let mut rays = 0;
+        let mut total = 0.0;
+        for &sz in z_steps {
+            for sy in [-1.0, 0.0, 1.0] {
+                for sx in [-1.0, 0.0, 1.0] {
+                    if (sx, sy, sz) == (0.0, 0.0, 0.0) {
+                        continue;
+                    }
+                    let norm = f64::sqrt(sx * sx + sy * sy + sz * sz);
+                    let direction = (sx / norm, sy / norm, sz / norm);
+                    
+                    let mut farthest = 0.0;
+                    for step in 0.. {
+                        let r = step as f64 * step_length;
+                        let position = (center.0 + r * direction.0, center.1 + r * direction.1, center.2 + r * direction.2);
+                        match self.information_at(position) {
+                            Some(info) if info.density() > threshold => farthest = r,
+                            Some(_) => {}
+                            None => break,
+                        }
+                    }
+                    total += farthest;
+                    rays += 1;
+                }
+            }
+        }
+        total / rays as f64
+    }
+    
+    /// Net current Σ J·n̂ ΔA through the grid plane normal to `axis` nearest `coordinate`
+    /// 
+    /// Positive when information flows toward +`axis`. ΔA is the cell face
+    /// area in the plane (Δx², or Δx on a 2D field where the plane is a
+    /// line). Coordinates off the grid select the face plane. Returns `None`
+    /// for `Axis::Z` on a 2D field, which has no z extent.
+    pub fn flux_through_plane(&self, axis: Axis, coordinate: f64) -> Option<f64> {
+        if self.planar && axis == Axis::Z {
+            return None;
+        }
+        let (nx, ny, nz) = self.shape;
+        let (dx, dy, dz) = self.spacing();
+        let plane = self.axis_index(axis, coordinate);
+        // In-plane cell counts (a, b) and the area of one cell face
+        let ((count_a, count_b), area) = match axis {
+            Axis::X => ((ny, nz), if self.planar { dy } else { dy * dz }),
+            Axis::Y => ((nx, nz), if self.planar { dx } else { dx * dz }),
+            Axis::Z => ((nx, ny), dx * dy),
+        };
+        
+        let mut flux = 0.0;
+        for b in 0..count_b {
+            for a in 0..count_a {
+                flux += match axis {
+                    Axis::X => self.flow_velocity(plane, a, b).0,
+                    Axis::Y => self.flow_velocity(a, plane, b).1,
+                    Axis::Z => self.flow_velocity(a, b, plane).2,
+                };
+            }
+        }
+        Some(flux * area)
+    }
+    
+    /// ∇·J at every cell, x-fastest like `as_slice`
+    /// 
+    /// Matches `divergence` cell by cell. In a steady state the diffusive
+    /// flux balances the intrinsic rate, ∇·J = -ℐ(1-ℐ/ℐ_max) + ε²ℐ, so ∇·J ≈ 0
+    /// wherever that rate vanishes.
+    pub fn divergence_field(&self) -> Vec<((f64, f64, f64), f64)> {
+        let current: Vec<(f64, f64, f64)> = self.current_field().into_iter().map(|(_, j)| j).collect();
+        let at = |i: usize, j: usize, k: usize| current[self.index(i, j, k)];
+        (0..self.field.len()).map(|idx| {
+            let (i, j, k) = self.cell_indices(idx);
+            let divergence = self.axis_derivative(Axis::X, i, &|c| at(c, j, k).0)
+                + self.axis_derivative(Axis::Y, j, &|c| at(i, c, k).1)
+                + self.axis_derivative(Axis::Z, k, &|c| at(i, j, c).2);
+            (self.cell_position(i, j, k), divergence)
+        }).collect()
+    }
+    
+    /// Laplacian ∇²ℐ at position
+    /// 
+    /// The same six-neighbour stencil `evolve` applies, each second difference
+    /// divided by Δxₐ² for the real grid spacing: the diffusion term of one
+    /// step is `D·Δx²·∇²ℐ`.
+    /// On the domain faces a missing neighbour is mirrored from the interior
+    /// side (zero-flux), since `evolve` itself never updates face cells.
+    /// On a 2D field the stencil has four neighbours.
+    /// Returns `None` where `contains` is false.
+    pub fn laplacian(&self, position: (f64, f64, f64)) -> Option<f64> {
+        let (i, j, k) = self.position_to_cell(position)?;
+        let density = |i: usize, j: usize, k: usize| self.field[self.index(i, j, k)].density();
+        let center = density(i, j, k);
+        
+        // Second derivative along one axis, mirroring across a face
+        let second = |axis: Axis, c: usize, at: &dyn Fn(usize) -> f64| {
+            let (n, spacing) = self.axis_grid(axis);
+            let lo = if c == 0 { 1 } else { c - 1 };
+            let hi = if c == n - 1 { n - 2 } else { c + 1 };
+            (at(lo) + at(hi) - 2.0 * center) / (spacing * spacing)
+        };
+        
+        let second_z = if self.planar { 0.0 } else { second(Axis::Z, k, &|c| density(i, j, c)) };
+        Some(second(Axis::X, i, &|c| density(c, j, k)) + second(Axis::Y, j, &|c| density(i, c, k)) + second_z)
+    }
+    
+    /// Total information in field
+    /// 
+    /// O(1): totalled exactly by every `evolve` and `evolve_backward` step
+    /// and maintained incrementally by other changes. After
+    /// `iter_cells_mut`, a full scan until the next mutation.
+    pub fn total_information(&self) -> f64 {
+        if self.totals_stale { self.scan_totals().0 } else { self.total_information }
+    }
+    
+    /// Count conscious points
+    /// 
+    /// O(1): totalled exactly by every `evolve` and `evolve_backward` step
+    /// and maintained incrementally by other changes. After
+    /// `iter_cells_mut`, a full scan until the next mutation.
+    pub fn conscious_count(&self) -> usize {
+        if self.totals_stale { self.scan_totals().1 } else { self.conscious_count }
+    }
+    
+    /// Number of grid points: resolution³ (resolution² for a 2D field, nx·ny·nz for `new_rect`)
+    pub fn grid_point_count(&self) -> usize {
+        self.field.len()
+    }
+    
+    /// Fraction of grid points above the integration threshold
+    pub fn conscious_fraction(&self) -> f64 {
+        self.conscious_count() as f64 / self.grid_point_count() as f64
+    }
+    
+    /// Order parameter of the consciousness transition: the conscious fraction
+    /// 
+    /// 0 in the disordered (subconscious) phase, 1 when fully ordered.
+    pub fn order_parameter(&self) -> f64 {
+        self.conscious_fraction()
+    }
+    
+    /// Spatial variance of density over all cells: ⟨ℐ²⟩ - ⟨ℐ⟩²
+    pub fn information_variance(&self) -> f64 {
+        let count = self.field.len() as f64;
+        let mean = self.total_information() / count;
+        self.field.iter().map(|info| (info.density() - mean).powi(2)).sum::<f64>() / count
+    }
+    
+    /// Cell counts in `bins` equal-width density bins spanning `range`
+    /// 
+    /// Bins are half-open except the last, which includes `range.1`;
+    /// densities outside `range` are not counted. Panics if `bins` is 0 or
+    /// the range is empty.
+    pub fn density_histogram(&self, bins: usize, range: (f64, f64)) -> Vec<usize> {
+        assert!(bins > 0 && range.0 < range.1, "density_histogram requires bins > 0 and min < max");
+        let width = (range.1 - range.0) / bins as f64;
+        let mut counts = vec![0; bins];
+        for info in &self.field {
+            let density = info.density();
+            if (range.0..=range.1).contains(&density) {
+                let bin = (((density - range.0) / width) as usize).min(bins - 1);
+                counts[bin] += 1;
+            }
+        }
+        counts
+    }
+    
+    /// Susceptibility χ = (⟨ℐ²⟩ - ⟨ℐ⟩²)/T, with T the `effective_temperature`
+    /// 
+    /// The fluctuation-response form χ = Var/T: density fluctuations per
+    /// unit of excess information, on the same temperature scale as the
+    /// thermal observables. χ is zero for a uniform field and peaks where
+    /// fluctuations are large for the field's temperature, as near a
+    /// critical point. Zero when T ≤ 0 - pure or depleted vacuum - where no
+    /// temperature scale exists.
+    pub fn susceptibility(&self) -> f64 {
+        let temperature = self.effective_temperature();
+        if temperature > 0.0 { self.information_variance() / temperature } else { 0.0 }
+    }
+    
+    /// Effective temperature: mean excess density over vacuum, ⟨ℐ⟩ - ℐ_vac
+    /// 
+    /// The one temperature scale for thermal experiments: zero for pure
+    /// vacuum, rising as information is created or deposited, negative for
+    /// a field depleted below vacuum. Equals `information_created` per cell,
+    /// so the temperature change over a run is its creation per cell.
+    pub fn effective_temperature(&self) -> f64 {
+        self.information_created() / self.field.len() as f64
+    }
+    
+    /// Check if any point is conscious
+    pub fn is_conscious(&self) -> bool {
+        self.conscious_count() > 0
+    }
+    
+    /// Current vacuum density
+    pub fn vacuum_density(&self) -> f64 {
+        vacuum_at_cosmic_time(self.cosmic_age)
+    }
+    
+    /// Vacuum scale factor a(t) = ℐ_vac(t)/ℐ_vac(0) = e^(αt)
+    pub fn vacuum_scale_factor(&self) -> f64 {
+        self.vacuum_density() / VACUUM_INFORMATION
+    }
+    
+    /// Comoving density at position: ℐ/a(t)
+    /// 
+    /// Measures structure relative to the exponentially growing vacuum, so a
+    /// fixed fractional overdensity reads the same at every cosmic epoch.
+    pub fn comoving_density_at(&self, position: (f64, f64, f64)) -> Option<f64> {
+        self.information_at(position).map(|info| info.density() / self.vacuum_scale_factor())
+    }
+    
+    /// Information created above vacuum
+    pub fn information_created(&self) -> f64 {
+        let vacuum_total = self.vacuum_density() * self.field.len() as f64;
+        self.total_information() - vacuum_total
+    }
+    
+    /// Simulated time: the sum of dt over every step taken
+    /// 
+    /// Follows `set_dt` and `evolve_adaptive` (so it need not equal
+    /// step·dt) and runs backward under `evolve_backward`. Saved, loaded and
+    /// stamped on every `HistoryFrame`.
+    pub fn time(&self) -> f64 { self.time }
+    
+    /// Steps taken by every integrator, carried by snapshots and saved files
+    pub fn step(&self) -> u64 { self.step }
+    
+    /// Get cosmic age
+    pub fn cosmic_age(&self) -> f64 { self.cosmic_age }
+    
+    /// Grid spacing Δx between neighbouring cells along x, in physical units
+    pub fn dx(&self) -> f64 { self.spacing().0 }
+    
+    /// Grid spacing (Δx, Δy, Δz) per axis; Δz is 0 on a 2D field
+    pub fn spacing(&self) -> (f64, f64, f64) {
+        grid_spacing(self.shape, self.extents)
+    }
+    
+    /// Cells per axis (N; the grid has N³ cells, N² on a 2D field)
+    /// 
+    /// The x count on a `new_rect` grid; see `shape`.
+    pub fn resolution(&self) -> usize { self.shape.0 }
+    
+    /// Cells along (x, y, z); z is 1 on a 2D field
+    pub fn shape(&self) -> (usize, usize, usize) { self.shape }
+    
+    /// (min, max) coordinate along every axis
+    /// 
+    /// The x extent on a `new_rect` grid; see `extents`.
+    pub fn bounds(&self) -> (f64, f64) { self.extents.0 }
+    
+    /// (min, max) coordinate along x, y and z
+    pub fn extents(&self) -> Extents { self.extents }
+    
+    /// Current time step
+    pub fn dt(&self) -> f64 { self.dt }
+    
+    /// Diffusion coefficient D (the per-axis mean after `set_diffusion_xyz`)
+    pub fn diffusion(&self) -> f64 { self.diffusion }
+    
+    /// `propagation_speed` of this field's D, in grid cells per unit time
+    /// 
+    /// Multiply by `dx` to compare with wavefront speeds in physical units.
+    pub fn propagation_speed(&self) -> f64 {
+        propagation_speed(self.diffusion)
+    }
+    
+    /// L2 norm of the cellwise density difference: √Σ(ℐ_self - ℐ_other)²
+    /// 
+    /// Panics if the two fields have different resolutions.
+    pub fn difference(&self, other: &Reality) -> f64 {
+        assert_eq!(self.field.len(), other.field.len(), "difference requires matching grids");
+        self.field.iter()
+            .zip(&other.field)
+            .map(|(a, b)| (a.density() - b.density()).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+    
+    /// Anneal initial deposits toward a target pattern
+    /// 
+    /// Stochastic hill-climbing for inverse design: deposits are proposed on
+    /// cells where the evolved trial field falls short of (or overshoots) the
+    /// target, and kept only if they reduce the post-evolution `difference`.
+    /// Each trial evolves for as many steps as the target is ahead of `self`.
+    /// 
+    /// On return `self` holds the best initial condition found (not evolved).
+    /// Returns the final difference norm. Same seed, same result.
+    pub fn anneal_toward(&mut self, target: &Reality, iterations: usize, seed: u64) -> f64 {
+        let steps = target.step.saturating_sub(self.step);
+        let mut rng = SplitMix64::new(seed);
+        
+        let evolve_trial = |deposits: &[(usize, f64)]| {
+            let mut trial = self.scratch();
+            for &(idx, amplitude) in deposits {
+                let current = trial.field[idx].density();
+                trial.set_cell(idx, trial.information(current + amplitude));
+            }
+            for _ in 0..steps {
+                trial.evolve();
+            }
+            trial
+        };
+        
+        let mut deposits: Vec<(usize, f64)> = Vec::new();
+        let mut best = evolve_trial(&deposits);
+        let mut best_difference = best.difference(target);
+        
+        for _ in 0..iterations {
+            // Pick a cell with probability ∝ squared residual
+            let residuals: Vec<f64> = target.field.iter()
+                .zip(&best.field)
+                .map(|(t, b)| t.density() - b.density())
+                .collect();
+            let total_weight: f64 = residuals.iter().map(|r| r * r).sum();
+            if total_weight == 0.0 {
+                break;
+            }
+            
+            let mut pick = rng.next_f64() * total_weight;
+            let mut idx = residuals.len() - 1;
+            for (i, r) in residuals.iter().enumerate() {
+                pick -= r * r;
+                if pick <= 0.0 {
+                    idx = i;
+                    break;
+                }
+            }
+            
+            let amplitude = residuals[idx] * rng.range(0.5, 1.5);
+            let mut candidate = deposits.clone();
+            candidate.push((idx, amplitude));
+            
+            let trial = evolve_trial(&candidate);
+            let trial_difference = trial.difference(target);
+            if trial_difference < best_difference {
+                deposits = candidate;
+                best = trial;
+                best_difference = trial_difference;
+            }
+        }
+        
+        for (idx, amplitude) in deposits {
+            let current = self.field[idx].density();
+            self.set_cell(idx, self.information(current + amplitude));
+        }
+        
+        best_difference
+    }
+    
+    // Private helpers
+    
+    /// Clone for internal trial runs, without the mutation log
+    fn scratch(&self) -> Reality {
+        let mut scratch = self.clone();
+        scratch.recording = None;
+        scratch.probes.clear();
+        scratch.enable_history(0);
+        scratch.creation = None;
+        scratch
+    }
+    
+    /// ℐ ← ℐ + h·∂ℐ/∂t for every cell, clamped
+    /// 
+    /// The new field is built in a fresh buffer from the unchanged previous
+    /// one and swapped in. Each `REDUCTION_CHUNK` of cells is totalled as it
+    /// is written and the chunk results are added in order - the reduction
+    /// tree of `scan_totals` - so the totals are exact every step and the
+    /// parallel and sequential builds agree bit for bit.
+    fn euler_update(&mut self, h: f64) {
+        let rates = self.rates(&self.field);
+        let before = self.total_information();
+        let mut creation = self.creation.take();
+        #[cfg(feature = "parallel")]
+        let (field, partials) = self.euler_chunks_parallel(&rates, h, creation.as_deref_mut());
+        #[cfg(not(feature = "parallel"))]
+        let (field, partials) = self.euler_chunks_sequential(&rates, h, creation.as_deref_mut());
+        self.creation = creation;
+        
+        let mut residual: f64 = 0.0;
+        let mut balance = InfoBalance::default();
+        for &(_, chunk_residual, chunk_balance) in &partials {
+            residual = residual.max(chunk_residual);
+            balance.source += chunk_balance.source;
+            balance.sink += chunk_balance.sink;
+            balance.boundary_flux += chunk_balance.boundary_flux;
+        }
+        self.field = field;
+        (self.total_information, self.conscious_count) = sum_totals(partials.into_iter().map(|(totals, _, _)| totals));
+        self.totals_stale = false;
+        balance.change = self.total_information - before;
+        self.last_balance = balance;
+        self.last_residual = residual;
+    }
+    
+    /// `euler_update`'s new field and per-chunk results, one chunk per rayon task
+    #[cfg(feature = "parallel")]
+    fn euler_chunks_parallel(&self, rates: &[f64], h: f64, creation: Option<&mut [f64]>) -> (Vec<Information>, Vec<EulerChunk>) {
+        let mut field = vec![Information(0.0); self.field.len()];
+        let creation = creation_chunks(creation, field.len());
+        let partials = field.par_chunks_mut(REDUCTION_CHUNK)
+            .zip(creation)
+            .enumerate()
+            .map(|(chunk, (out, creation))| self.euler_chunk(chunk * REDUCTION_CHUNK, rates, h, out, creation))
+            .collect();
+        (field, partials)
+    }
+    
+    /// `euler_update`'s new field and per-chunk results, one chunk at a time
+    /// 
+    /// Also the reference the parallel path is tested against.
+    #[cfg_attr(all(feature = "parallel", not(test)), allow(dead_code))]
+    fn euler_chunks_sequential(&self, rates: &[f64], h: f64, creation: Option<&mut [f64]>) -> (Vec<Information>, Vec<EulerChunk>) {
+        let mut field = vec![Information(0.0); self.field.len()];
+        let creation = creation_chunks(creation, field.len());
+        let partials = field.chunks_mut(REDUCTION_CHUNK)
+            .zip(creation)
+            .enumerate()
+            .map(|(chunk, (out, creation))| self.euler_chunk(chunk * REDUCTION_CHUNK, rates, h, out, creation))
+            .collect();
+        (field, partials)
+    }
+    
+    /// Euler-update cells `start..start + out.len()` into `out`
+    /// 
+    /// Reads only the previous field. Interior cells add their net creation
+    /// to `creation` (indexed from `start`) when tracking is on.
+    fn euler_chunk(&self, start: usize, rates: &[f64], h: f64, out: &mut [Information], mut creation: Option<&mut [f64]>) -> EulerChunk {
+        let nx = self.shape.0;
+        let mut residual: f64 = 0.0;
+        let mut balance = InfoBalance::default();
+        for (offset, new) in out.iter_mut().enumerate() {
+            let idx = start + offset;
+            let (old, rate) = (self.field[idx], rates[idx]);
+            // Face rates are zero, leaving those cells untouched
+            *new = self.information(old.density() + h * rate);
+            residual = residual.max((new.density() - old.density()).abs());
+            
+            let i = idx % nx;
+            if i == 0 || i == nx - 1 || !self.is_interior_row(idx / nx) {
+                continue;
+            }
+            let (source, sink) = (h * old.self_creation(self.max_information), h * self.uncertainty_sink(old));
+            balance.source += source;
+            balance.sink += sink;
+            balance.boundary_flux -= h * rate - (source - sink);
+            if let Some(creation) = creation.as_deref_mut() {
+                creation[offset] += source - sink;
+            }
+        }
+        (chunk_totals(out), residual, balance)
+    }
+    
+    /// ∂ℐ/∂t for every cell of `field` (zero on the fixed faces)
+    fn rates(&self, field: &[Information]) -> Vec<f64> {
+        #[cfg(feature = "parallel")]
+        return self.rates_parallel(field);
+        #[cfg(not(feature = "parallel"))]
+        return self.rates_sequential(field);
+    }
+    
+    /// `rates` computed on rayon, one x-row per task
+    #[cfg(feature = "parallel")]
+    fn rates_parallel(&self, field: &[Information]) -> Vec<f64> {
+        let mut rates = vec![0.0; field.len()];
+        rates.par_chunks_mut(self.shape.0).enumerate().for_each(|(row, out)| {
+            if self.is_interior_row(row) {
+                self.row_rates(field, row, out);
+            }
+        });
+        rates
+    }
+    
+    /// `rates` computed one x-row at a time
+    /// 
+    /// Also the reference the parallel path is tested against.
+    #[cfg_attr(all(feature = "parallel", not(test)), allow(dead_code))]
+    fn rates_sequential(&self, field: &[Information]) -> Vec<f64> {
+        let mut rates = vec![0.0; field.len()];
+        for (row, out) in rates.chunks_mut(self.shape.0).enumerate() {
+            if self.is_interior_row(row) {
+                self.row_rates(field, row, out);
+            }
+        }
+        rates
+    }
+    
+    /// ∂ℐ/∂t = D∇²ℐ - ε²ℐ + ℐ(1-ℐ/ℐ_max) along the interior of x-row `row`
+    /// 
+    /// The one spatial operator shared by every integrator: the six-neighbour
+    /// stencil in grid units (four-neighbour in 2D) plus the intrinsic rate.
+    /// With per-axis `diffusion_xyz` or a `new_rect` grid each second
+    /// difference carries its own coefficient. Face entries of `out` are
+    /// left untouched.
+    fn row_rates(&self, field: &[Information], row: usize, out: &mut [f64]) {
+        let (nx, ny, _) = self.shape;
+        let density = |idx: usize| field[idx].density();
+        
+        if !self.planar && self.diffusion_xyz.is_none() && self.is_cubic() {
+            stencil::laplacian_row(field, nx, row, out);
+            for (i, rate) in out.iter_mut().enumerate().take(nx - 1).skip(1) {
+                *rate = self.diffusion * *rate + self.intrinsic_rate(field[row * nx + i]);
+            }
+            return;
+        }
+        
+        let (d_x, d_y, d_z) = self.diffusion_axes();
+        let plane = nx * ny;
+        for (i, rate) in out.iter_mut().enumerate().take(nx - 1).skip(1) {
+            let idx = row * nx + i;
+            let center = density(idx);
+            let second_x = density(idx - 1) + density(idx + 1) - 2.0 * center;
+            let second_y = density(idx - nx) + density(idx + nx) - 2.0 * center;
+            let diffusion_term = if self.planar {
+                d_x * second_x + d_y * second_y
+            } else {
+                let second_z = density(idx - plane) + density(idx + plane) - 2.0 * center;
+                d_x * second_x + d_y * second_y + d_z * second_z
+            };
+            *rate = diffusion_term + self.intrinsic_rate(field[idx]);
+        }
+    }
+    
+    /// -ε²ℐ + ℐ(1-ℐ/ℐ_max) with this field's ℐ_max and ε
+    fn intrinsic_rate(&self, info: Information) -> f64 {
+        info.self_creation(self.max_information) - self.uncertainty_sink(info)
+    }
+    
+    /// ε²ℐ with this field's ε
+    fn uncertainty_sink(&self, info: Information) -> f64 {
+        let epsilon = match &self.uncertainty_fn {
+            Some(f) => f(info.density()),
+            None => info.uncertainty(),
+        };
+        epsilon.powi(2) * info.density()
+    }
+    
+    /// Whether x-row `row` = k·Ny + j lies off the y and z faces
+    fn is_interior_row(&self, row: usize) -> bool {
+        let (_, ny, nz) = self.shape;
+        let (j, k) = (row % ny, row / ny);
+        let interior_k = if self.planar { true } else { k > 0 && k < nz - 1 };
+        j > 0 && j < ny - 1 && interior_k
+    }
+    
+    /// Explicit stability limit on D·dt for this grid's dimension
+    fn stability_limit(&self) -> f64 {
+        if self.planar { MAX_STABILITY_NUMBER_2D } else { MAX_STABILITY_NUMBER }
+    }
+    
+    /// Largest dt keeping `stability_number` within the stability limit
+    fn max_stable_dt(&self) -> f64 {
+        let diffusion = self.effective_diffusion();
+        if diffusion > 0.0 { self.stability_limit() / diffusion } else { f64::INFINITY }
+    }
+    
+    /// D scaled by the grid's mean Laplacian weight: `stability_number` per unit dt
+    fn effective_diffusion(&self) -> f64 {
+        self.diffusion * mean_spacing_weight(self.shape, self.extents, self.planar)
+    }
+    
+    fn sample_probes(&mut self) {
+        for p in 0..self.probes.len() {
+            let position = self.probes[p].position;
+            if let Some(info) = self.information_at(position) {
+                self.probes[p].samples.push((self.time, info.density()));
+            }
+        }
+    }
+    
+    /// Reset the pinned cells to their held densities
+    fn apply_pins(&mut self) {
+        for p in 0..self.pins.len() {
+            let (idx, info) = self.pins[p];
+            self.set_cell(idx, info);
+        }
+    }
+    
+    /// Clamp the field to [0, ℐ_max] if `set_clamping` is on
+    fn apply_clamping(&mut self) {
+        if !self.clamping {
+            return;
+        }
+        let max_information = self.max_information;
+        for info in &mut self.field {
+            if !(0.0..=max_information).contains(&info.density()) {
+                let density = if info.density().is_nan() { 0.0 } else { info.density() };
+                *info = Information(density.clamp(0.0, max_information));
+                // A NaN poisons the running totals: rescan rather than patch
+                self.totals_stale = true;
+            }
+        }
+    }
+    
+    fn record_history(&mut self) {
+        if self.history_every == 0 || !self.step.is_multiple_of(self.history_every as u64) {
+            return;
+        }
+        let max_consciousness = self.max_density().max(0.0);
+        self.history.push(HistoryFrame {
+            step: self.step,
+            time: self.time,
+            total_information: self.total_information(),
+            conscious_count: self.conscious_count(),
+            max_consciousness,
+        });
+    }
+    
+    fn log(&mut self, op: Op) {
+        if let Some((_, ops)) = &mut self.recording {
+            ops.push(op);
+        }
+    }
+    
+    /// Write a cell, keeping the running totals in sync
+    fn set_cell(&mut self, idx: usize, info: Information) {
+        if self.totals_stale {
+            self.recount();
+        }
+        self.record_change(self.field[idx], info);
+        self.field[idx] = info;
+    }
+    
+    /// Apply one cell's change to the running totals
+    fn record_change(&mut self, old: Information, new: Information) {
+        self.total_information += new.density() - old.density();
+        if new.is_conscious() != old.is_conscious() {
+            if new.is_conscious() {
+                self.conscious_count += 1;
+            } else {
+                self.conscious_count -= 1;
+            }
+        }
+    }
+    
+    /// Recompute the running totals with a full scan
+    pub(crate) fn recount(&mut self) {
+        (self.total_information, self.conscious_count) = self.scan_totals();
+        self.totals_stale = false;
+    }
+    
+    /// (total information, conscious count) by a full scan of the field
+    /// 
+    /// Densities are summed per fixed-size chunk and the chunk sums added in
+    /// order, so the parallel and sequential builds agree bit for bit
+    /// whatever the thread count.
+    fn scan_totals(&self) -> (f64, usize) {
+        #[cfg(feature = "parallel")]
+        let partials: Vec<(f64, usize)> = self.field.par_chunks(REDUCTION_CHUNK).map(chunk_totals).collect();
+        #[cfg(not(feature = "parallel"))]
+        let partials: Vec<(f64, usize)> = self.field.chunks(REDUCTION_CHUNK).map(chunk_totals).collect();
+        sum_totals(partials)
+    }
+    
+    fn index(&self, i: usize, j: usize, k: usize) -> usize {
+        let (nx, ny, _) = self.shape;
+        (k * ny + j) * nx + i
+    }
+    
+    /// Grid indices (i, j, k) of flat index `idx`
+    fn cell_indices(&self, idx: usize) -> (usize, usize, usize) {
+        let (nx, ny, _) = self.shape;
+        (idx % nx, (idx / nx) % ny, idx / (nx * ny))
+    }
+    
+    fn index_position(&self, idx: usize) -> (f64, f64, f64) {
+        let (i, j, k) = self.cell_indices(idx);
+        self.cell_position(i, j, k)
+    }
+    
+    fn cell_position(&self, i: usize, j: usize, k: usize) -> (f64, f64, f64) {
+        let (dx, dy, dz) = self.spacing();
+        let ((x0, _), (y0, _), (z0, _)) = self.extents;
+        (
+            x0 + i as f64 * dx,
+            y0 + j as f64 * dy,
+            if self.planar { 0.0 } else { z0 + k as f64 * dz },
+        )
+    }
+    
+    /// ∇ℐ at cell (i, j, k): central differences, one-sided on faces
+    fn cell_gradient(&self, i: usize, j: usize, k: usize) -> (f64, f64, f64) {
+        let density = |i: usize, j: usize, k: usize| self.field[self.index(i, j, k)].density();
+        (
+            self.axis_derivative(Axis::X, i, &|c| density(c, j, k)),
+            self.axis_derivative(Axis::Y, j, &|c| density(i, c, k)),
+            self.axis_derivative(Axis::Z, k, &|c| density(i, j, c)),
+        )
+    }
+    
+    /// Flow velocity v = -D∇ℐ at cell (i, j, k), with D = diffusion·Δxₐ² per axis
+    fn flow_velocity(&self, i: usize, j: usize, k: usize) -> (f64, f64, f64) {
+        let (dx, dy, dz) = self.spacing();
+        let (d_x, d_y, d_z) = self.diffusion_axes();
+        let (gx, gy, gz) = self.cell_gradient(i, j, k);
+        (-d_x * dx * dx * gx, -d_y * dy * dy * gy, -d_z * dz * dz * gz)
+    }
+    
+    /// Stencil coefficient along (x, y, z) in grid units
+    /// 
+    /// The per-axis diffusion, weighted by (Δx/Δxₐ)² on a rectangular grid.
+    fn diffusion_axes(&self) -> (f64, f64, f64) {
+        let (d_x, d_y, d_z) = self.diffusion_xyz.unwrap_or((self.diffusion, self.diffusion, self.diffusion));
+        let (w_x, w_y, w_z) = spacing_weights(self.shape, self.extents);
+        (d_x * w_x, d_y * w_y, d_z * w_z)
+    }
+    
+    /// Whether every axis has the same cell count and spacing
+    fn is_cubic(&self) -> bool {
+        let (nx, ny, nz) = self.shape;
+        let (dx, dy, dz) = self.spacing();
+        nx == ny && ny == nz && dx == dy && dy == dz
+    }
+    
+    /// Index along `axis` of the grid plane nearest `coordinate`
+    /// 
+    /// Clamped onto the faces; always 0 along z on a 2D field.
+    pub(crate) fn axis_index(&self, axis: Axis, coordinate: f64) -> usize {
+        let (count, spacing) = self.axis_grid(axis);
+        if count == 1 {
+            return 0;
+        }
+        let (min_bound, _) = match axis {
+            Axis::X => self.extents.0,
+            Axis::Y => self.extents.1,
+            Axis::Z => self.extents.2,
+        };
+        ((coordinate - min_bound) / spacing).round().clamp(0.0, (count - 1) as f64) as usize
+    }
+    
+    /// Cell count and spacing along `axis`
+    fn axis_grid(&self, axis: Axis) -> (usize, f64) {
+        let (dx, dy, dz) = self.spacing();
+        match axis {
+            Axis::X => (self.shape.0, dx),
+            Axis::Y => (self.shape.1, dy),
+            Axis::Z => (self.shape.2, dz),
+        }
+    }
+    
+    /// First derivative along `axis` through grid coordinate `c`
+    /// 
+    /// `at` reads the quantity at a coordinate along that axis. Central
+    /// difference in the interior, one-sided on the faces; zero along z on
+    /// a 2D field.
+    fn axis_derivative(&self, axis: Axis, c: usize, at: &dyn Fn(usize) -> f64) -> f64 {
+        if self.planar && axis == Axis::Z {
+            return 0.0;
+        }
+        let (count, spacing) = self.axis_grid(axis);
+        let (lo, hi) = (c.saturating_sub(1), (c + 1).min(count - 1));
+        (at(hi) - at(lo)) / ((hi - lo) as f64 * spacing)
+    }
+    
+    /// Vacuum copy of this field's settings on a `shape` grid over the same extents
+    /// 
+    /// Diffusion is rescaled to keep the physical coefficient D·Δx² and dt
+    /// shrinks to the stability limit if needed. Probes, history and the op
+    /// log are left behind.
+    fn regridded(&self, shape: (usize, usize, usize)) -> Reality {
+        let scale = (self.dx() / grid_spacing(shape, self.extents).0).powi(2);
+        let mut reality = Reality {
+            field: vec![self.information(self.vacuum_density()); shape.0 * shape.1 * shape.2],
+            shape,
+            extents: self.extents,
+            diffusion: self.diffusion * scale,
+            dt: self.dt,
+            time: self.time,
+            step: self.step,
+            cosmic_age: self.cosmic_age,
+            total_information: 0.0,
+            conscious_count: 0,
+            recording: None,
+            probes: Vec::new(),
+            history_every: 0,
+            history: Vec::new(),
+            creation: None,
+            pins: Vec::new(),
+            dt_limits: self.dt_limits,
+            totals_stale: false,
+            diffusion_xyz: self.diffusion_xyz.map(|(x, y, z)| (x * scale, y * scale, z * scale)),
+            last_balance: InfoBalance::default(),
+            last_residual: 0.0,
+            max_information: self.max_information,
+            uncertainty_fn: self.uncertainty_fn.clone(),
+            clamping: self.clamping,
+            planar: self.planar,
+        };
+        reality.dt = reality.dt.min(reality.max_stable_dt());
+        reality.recount();
+        reality
+    }
+    
+    /// Whether `position` lies in the box `min`..=`max` (z ignored on a 2D field)
+    fn box_contains(&self, min: (f64, f64, f64), max: (f64, f64, f64), (x, y, z): (f64, f64, f64)) -> bool {
+        let inside_z = self.planar || (min.2..=max.2).contains(&z);
+        (min.0..=max.0).contains(&x) && (min.1..=max.1).contains(&y) && inside_z
+    }
+    
+    /// Information at `density`, clamped to [0, ℐ_max] of this field
+    fn information(&self, density: f64) -> Information {
+        Information(density.clamp(0.0, self.max_information))
+    }
+    
+    /// Σℐ·ΔV over the cells whose centers satisfy `inside`
+    fn information_where(&self, inside: impl Fn((f64, f64, f64)) -> bool) -> f64 {
+        let (dx, dy, dz) = self.spacing();
+        let cell_volume = if self.planar { dx * dy } else { dx * dy * dz };
+        let sum: f64 = self.field.iter().enumerate()
+            .filter(|&(idx, _)| inside(self.index_position(idx)))
+            .map(|(_, info)| info.density())
+            .sum();
+        sum * cell_volume
+    }
+    
+    /// Nearest cell (i, j, k) to a position, or `None` where `contains` is false
+    fn position_to_cell(&self, position: (f64, f64, f64)) -> Option<(usize, usize, usize)> {
+        self.position_to_index(position).ok().map(|idx| self.cell_indices(idx))
+    }
+    
+    fn position_to_index(&self, (x, y, z): (f64, f64, f64)) -> Result<usize, ()> {
+        // Round in f64: a negative index must not saturate onto the face
+        let cell = |c: f64, axis: Axis, (min_bound, _): (f64, f64)| {
+            let (count, spacing) = self.axis_grid(axis);
+            let c = ((c - min_bound) / spacing).round();
+            if (0.0..count as f64).contains(&c) { Ok(c as usize) } else { Err(()) }
+        };
+        let k = if self.planar { 0 } else { cell(z, Axis::Z, self.extents.2)? };
+        Ok(self.index(cell(x, Axis::X, self.extents.0)?, cell(y, Axis::Y, self.extents.1)?, k))
+    }
+    
+
+}
+
+/// Estimate the critical initial density of the consciousness transition
+/// 
+/// Sweeps the initial density from `MIN_INFORMATION` to `MAX_INFORMATION`
+/// in steps of `CRITICAL_SWEEP_STEP`; see
+/// `find_critical_threshold_with_densities` for the method and for a
+/// coarser or narrower grid.
+pub fn find_critical_threshold(builder: impl Fn(f64) -> Reality + Sync, steps: usize) -> f64 {
+    let count = ((MAX_INFORMATION - MIN_INFORMATION) / CRITICAL_SWEEP_STEP).round() as usize;
+    let densities: Vec<f64> = (0..=count).map(|i| MIN_INFORMATION + CRITICAL_SWEEP_STEP * i as f64).collect();
+    find_critical_threshold_with_densities(builder, &densities, steps)
+}
+
+/// Estimate the critical initial density over an explicit density grid
+/// 
+/// Builds one field per entry of the ascending `densities` with `builder`,
+/// evolves each `steps` steps and takes its `order_parameter`; the runs are
+/// independent and go through `sweep`. Returns the
+/// midpoint of the interval where the order parameter rises most steeply,
+/// so the estimate is as fine as the sweep.
+/// 
+/// Panics with fewer than two densities.
+pub fn find_critical_threshold_with_densities(
+    builder: impl Fn(f64) -> Reality + Sync,
+    densities: &[f64],
+    steps: usize,
+) -> f64 {
+    assert!(densities.len() >= 2, "find_critical_threshold needs at least two densities");
+    let order = crate::sweep::sweep(densities, |&density| {
+        let mut reality = builder(density);
+        for _ in 0..steps {
+            reality.evolve();
+        }
+        reality.order_parameter()
+    });
+    
+    let mut steepest = (f64::NEG_INFINITY, 0.0);
+    for (d, m) in densities.windows(2).zip(order.windows(2)) {
+        let slope = (m[1] - m[0]) / (d[1] - d[0]);
+        if slope > steepest.0 {
+            steepest = (slope, 0.5 * (d[0] + d[1]));
+        }
+    }
+    steepest.1
+}
+
+/// Solve (1 + 2c)xᵢ - c(xᵢ₋₁ + xᵢ₊₁) = rᵢ in place, x₀ and xₘ₋₁ held at 0
+/// 
+/// Thomas algorithm over the interior of one grid line; `line` holds r on
+/// entry and x on return.
+fn solve_crank_nicolson_line(line: &mut [f64], coupling: f64) {
+    let m = line.len();
+    if m < 3 {
+        return;
+    }
+    let diagonal = 1.0 + 2.0 * coupling;
+    let mut upper = vec![0.0; m];
+    let mut pivot = diagonal;
+    line[1] /= pivot;
+    for i in 2..m - 1 {
+        upper[i - 1] = -coupling / pivot;
+        pivot = diagonal + coupling * upper[i - 1];
+        line[i] = (line[i] + coupling * line[i - 1]) / pivot;
+    }
+    for i in (1..m - 2).rev() {
+        line[i] -= upper[i] * line[i + 1];
+    }
+    line[0] = 0.0;
+    line[m - 1] = 0.0;
+}
+
+/// Spacing (Δx, Δy, Δz) of a grid; 0 along an axis with a single layer
+fn grid_spacing(shape: (usize, usize, usize), extents: Extents) -> (f64, f64, f64) {
+    let spacing = |(min, max): (f64, f64), count: usize| {
+        if count > 1 { (max - min) / (count - 1) as f64 } else { 0.0 }
+    };
+    (spacing(extents.0, shape.0), spacing(extents.1, shape.1), spacing(extents.2, shape.2))
+}
+
+/// Laplacian weights (Δx/Δxₐ)² per axis, relative to the x spacing
+fn spacing_weights(shape: (usize, usize, usize), extents: Extents) -> (f64, f64, f64) {
+    let (dx, dy, dz) = grid_spacing(shape, extents);
+    let weight = |d: f64| if d > 0.0 { (dx / d).powi(2) } else { 0.0 };
+    (weight(dx), weight(dy), weight(dz))
+}
+
+/// Mean Laplacian weight over the grid's axes: exactly 1 for equal spacing
+fn mean_spacing_weight(shape: (usize, usize, usize), extents: Extents, planar: bool) -> f64 {
+    let (w_x, w_y, w_z) = spacing_weights(shape, extents);
+    if planar { (w_x + w_y) / 2.0 } else { (w_x + w_y + w_z) / 3.0 }
+}
+
+/// Per-`REDUCTION_CHUNK` creation tracking slices for `euler_update`
+/// 
+/// One `None` per chunk of a `len`-cell field when tracking is off.
+fn creation_chunks(creation: Option<&mut [f64]>, len: usize) -> Vec<Option<&mut [f64]>> {
+    match creation {
+        Some(creation) => creation.chunks_mut(REDUCTION_CHUNK).map(Some).collect(),
+        None => (0..len.div_ceil(REDUCTION_CHUNK)).map(|_| None).collect(),
+    }
+}
+
+/// (total information, conscious count) of one `REDUCTION_CHUNK` of cells
+fn chunk_totals(chunk: &[Information]) -> (f64, usize) {
+    let total: f64 = chunk.iter().map(|i| i.density()).sum();
+    (total, chunk.iter().filter(|i| i.is_conscious()).count())
+}
+
+/// Add per-chunk totals in chunk order, the reduction tree of `scan_totals`
+fn sum_totals(partials: impl IntoIterator<Item = (f64, usize)>) -> (f64, usize) {
+    partials.into_iter().fold((0.0, 0), |(total, count), (t, c)| (total + t, count + c))
+}
+
+/// Saturated density: the stable fixed point where ℐ(1-ℐ/ℐ_max) = ε²(ℐ)ℐ
+fn saturation_density(max_information: f64) -> f64 {
+    let mut density = max_information;
+    for _ in 0..50 {
+        density = max_information * (1.0 - Information(density).uncertainty().powi(2));
+    }
+    density
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_consciousness_threshold() {
+        assert!(!Information::new(0.5).is_conscious());
+        assert!(Information::new(INTEGRATION_THRESHOLD).is_conscious());
+        assert!(Information::new(1.0).is_conscious());
+    }
+    
+    #[test]
+    fn test_uncertainty_matches_decay_term() {
+        let info = Information::new(1.5);
+        assert!((info.uncertainty() - 0.2).abs() < 1e-12);
+        assert_eq!(Information::new(MAX_INFORMATION).uncertainty(), MIN_UNCERTAINTY.max(0.5 / 17.0));
+        
+        let decay = info.intrinsic_rate() - 1.5 * (1.0 - 1.5 / MAX_INFORMATION);
+        assert!((decay + info.uncertainty().powi(2) * 1.5).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_threshold_margin_and_ratio() {
+        let at = Information::new(INTEGRATION_THRESHOLD);
+        assert_eq!(at.consciousness_margin(), 0.0);
+        assert_eq!(at.self_reference_ratio(), 1.0);
+        
+        let below = Information::new(0.5);
+        assert!(below.consciousness_margin() < 0.0 && below.self_reference_ratio() < 1.0);
+        assert!((Information::new(2.0).consciousness_margin() - (2.0 - INTEGRATION_THRESHOLD)).abs() < 1e-15);
+        assert!((Information::new(1.0).self_reference_ratio() - 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_information_creation() {
+        let info = Information::new(1.0);
+        assert!(info.intrinsic_rate() > 0.0);
+    }
+    
+    #[test]
+    fn test_iirt_equation() {
+        let mut reality = Reality::from_vacuum();
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        
+        let initial = reality.total_information();
+        reality.evolve();
+        let final_info = reality.total_information();
+        
+        assert!(final_info > initial);
+        assert!(reality.conscious_count() > 0);
+    }
+    
+    #[test]
+    fn test_anneal_toward_target() {
+        let mut target = Reality::new(16, (-1.0, 1.0), 1.0, 0.001);
+        target.add_information((0.0, 0.0, 0.0), 3.0);
+        for _ in 0..5 {
+            target.evolve();
+        }
+        
+        let mut vacuum_start = Reality::new(16, (-1.0, 1.0), 1.0, 0.001);
+        for _ in 0..5 {
+            vacuum_start.evolve();
+        }
+        let vacuum_difference = vacuum_start.difference(&target);
+        
+        let mut designed = Reality::new(16, (-1.0, 1.0), 1.0, 0.001);
+        let annealed_difference = designed.anneal_toward(&target, 20, 7);
+        
+        assert!(annealed_difference < vacuum_difference);
+        assert!(designed.information_at((0.0, 0.0, 0.0)).unwrap().density()
+            > vacuum_start.vacuum_density());
+    }
+    
+    #[test]
+    fn test_steady_diffusion_linear_ramp() {
+        let mut reality = Reality::new(8, (0.0, 7.0), 1.0, 0.001);
+        let mut fixed = Vec::new();
+        for j in 0..8 {
+            for k in 0..8 {
+                fixed.push(((0.0, j as f64, k as f64), 2.0));
+                fixed.push(((7.0, j as f64, k as f64), 9.0));
+            }
+        }
+        
+        assert!(reality.solve_steady_diffusion(&fixed).is_some());
+        
+        // The y and z faces are zero-flux rather than held as in `evolve`, so
+        // the ramp runs through them too
+        for i in 0..8 {
+            let expected = 2.0 + i as f64;
+            for (y, z) in [(3.0, 4.0), (0.0, 4.0), (7.0, 0.0)] {
+                let actual = reality.information_at((i as f64, y, z)).unwrap().density();
+                assert!((actual - expected).abs() < 1e-6, "x={}: {} vs {}", i, actual, expected);
+            }
+        }
+        
+        // Already steady: one sweep confirms it
+        assert_eq!(reality.solve_steady_diffusion(&fixed), Some(1));
+    }
+    
+    #[test]
+    fn test_steady_diffusion_follows_per_axis_coefficients() {
+        // x = 0 face at 2 and y = 0 face at 9: each cell relaxes to the face
+        // its axis couples it to
+        let solve = |diffusion_xyz: (f64, f64, f64)| {
+            let mut reality = Reality::new(6, (0.0, 5.0), 1.0, 0.001);
+            reality.set_diffusion_xyz(diffusion_xyz.0, diffusion_xyz.1, diffusion_xyz.2);
+            let mut fixed = Vec::new();
+            for a in 0..6 {
+                for k in 0..6 {
+                    fixed.push(((0.0, a as f64, k as f64), 2.0));
+                    fixed.push(((a as f64, 0.0, k as f64), 9.0));
+                }
+            }
+            assert!(reality.solve_steady_diffusion(&fixed).is_some());
+            reality.information_at((3.0, 3.0, 3.0)).unwrap().density()
+        };
+        
+        assert!((solve((1.0, 0.0, 0.0)) - 2.0).abs() < 1e-6);
+        assert!((solve((0.0, 1.0, 0.0)) - 9.0).abs() < 1e-6);
+        let isotropic = solve((1.0, 1.0, 1.0));
+        assert!(isotropic > 2.1 && isotropic < 8.9, "{}", isotropic);
+        assert!(solve((4.0, 1.0, 1.0)) < isotropic);
+    }
+    
+    #[test]
+    fn test_front_speed_matches_fisher_kpp() {
+        // Near-empty vacuum so the front invades an unstable ℐ ≈ 0 state; Δx = 1
+        let mut reality = Reality::new_at_cosmic_age(48, (0.0, 47.0), 1.0, 0.1, -40.0);
+        let saturated = saturation_density(MAX_INFORMATION);
+        for i in 1..12 {
+            for j in 1..47 {
+                for k in 1..47 {
+                    reality.add_information((i as f64, j as f64, k as f64), saturated);
+                }
+            }
+        }
+        
+        let speed = reality.front_speed(Axis::X, 120);
+        let growth_rate = 1.0 - Information::new(0.0).uncertainty().powi(2);
+        let theory = 2.0 * (1.0 * growth_rate).sqrt();
+        assert!((speed - theory).abs() / theory < 0.15, "speed {} vs theory {}", speed, theory);
+    }
+    
+    #[test]
+    fn test_region_equilibration() {
+        let mut reality = Reality::new(25, (-1.2, 1.2), 1.0, 0.001);
+        let saturated = saturation_density(MAX_INFORMATION);
+        for i in -9..=-3 {
+            for j in -3..=3 {
+                for k in -3..=3 {
+                    reality.add_information((i as f64 * 0.1, j as f64 * 0.1, k as f64 * 0.1), saturated);
+                }
+            }
+        }
+        for _ in 0..50 {
+            reality.evolve();
+        }
+        reality.add_information((0.6, 0.0, 0.0), 3.0);
+        
+        let settled = ((-0.7, -0.05, -0.05), (-0.6, 0.05, 0.05));
+        let fresh = ((0.55, -0.05, -0.05), (0.65, 0.05, 0.05));
+        assert_eq!(reality.region_equilibration(&[settled, fresh], 1e-4), vec![true, false]);
+    }
+    
+    #[test]
+    fn test_region_equilibration_on_a_plane_ignores_z() {
+        let mut plane = Reality::new_2d(11, (-1.0, 1.0), 1.0, 0.01);
+        plane.add_information((0.0, 0.0, 0.0), 3.0);
+        let off_plane = ((-0.25, -0.25, 0.5), (0.25, 0.25, 1.0));
+        assert_eq!(plane.region_equilibration(&[off_plane], 1e-4), vec![false]);
+    }
+    
+    #[test]
+    #[should_panic(expected = "holds no cells")]
+    fn test_region_equilibration_rejects_empty_regions() {
+        let reality = Reality::new(7, (-1.0, 1.0), 1.0, 0.01);
+        reality.region_equilibration(&[((0.01, 0.01, 0.01), (0.02, 0.02, 0.02))], 1e-4);
+    }
+    
+    #[test]
+    fn test_incremental_totals_match_rescan() {
+        let mut reality = Reality::new(20, (-1.0, 1.0), 1.0, 0.001);
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        reality.add_information((0.5, -0.3, 0.2), 1.2);
+        reality.add_information((-0.4, 0.4, 0.0), -5.0);
+        for step in 0..100 {
+            reality.evolve();
+            if step % 25 == 0 {
+                reality.add_information((0.1 * (step / 25) as f64, 0.0, 0.0), 2.0);
+            }
+        }
+        
+        let (rescanned_total, rescanned_count) = reality.scan_totals();
+        assert_eq!(reality.conscious_count(), rescanned_count);
+        assert_eq!(reality.total_information(), rescanned_total);
+    }
+    
+    #[test]
+    fn test_cached_totals_survive_every_mutator() {
+        let mut reality = Reality::new_at_cosmic_age(10, (-1.0, 1.0), 0.8, 0.01, 0.0);
+        let assert_clean = |reality: &Reality| {
+            assert!(!reality.totals_stale);
+            let (total, count) = reality.scan_totals();
+            assert_eq!(reality.conscious_count(), count);
+            assert!((reality.total_information() - total).abs() < 1e-9 * total);
+        };
+        
+        reality.add_information_batch(&[((0.0, 0.0, 0.0), 3.0), ((0.4, 0.2, -0.2), 1.5)]).unwrap();
+        reality.add_noise(0.3, 11);
+        let snapshot = reality.snapshot();
+        for _ in 0..10 {
+            reality.evolve();
+            reality.evolve_rk4();
+        }
+        reality.evolve_adaptive(1e-3);
+        assert_clean(&reality);
+        
+        for (_, _, _, _, info) in reality.iter_cells_mut() {
+            *info = Information::new(info.density() * 0.5);
+        }
+        reality.evolve();
+        assert_clean(&reality);
+        
+        reality.restore(&snapshot);
+        assert!(reality.solve_steady_diffusion(&[((-1.0, 0.0, 0.0), 2.0), ((1.0, 0.0, 0.0), 0.5)]).is_some());
+        assert_clean(&reality);
+    }
+    
+    #[test]
+    fn test_evolve_reporting_locates_bump() {
+        let mut reality = Reality::new(21, (-1.0, 1.0), 1.0, 0.001);
+        reality.add_information((0.3, -0.2, 0.1), 2.0);
+        
+        let report = reality.evolve_reporting();
+        let (x, y, z) = report.at;
+        assert!((x - 0.3).abs() < 1e-9 && (y + 0.2).abs() < 1e-9 && (z - 0.1).abs() < 1e-9);
+        assert!(report.max_change > 0.0);
+        assert_eq!(report.total_information, reality.total_information());
+    }
+    
+    #[test]
+    fn test_moment_of_inertia_ring_vs_blob() {
+        let mut ring = Reality::new_at_cosmic_age(21, (-1.0, 1.0), 1.0, 0.001, 0.0);
+        for n in 0..12 {
+            let angle = n as f64 * std::f64::consts::PI / 6.0;
+            ring.add_information((0.6 * angle.cos(), 0.6 * angle.sin(), 0.0), 0.5);
+        }
+        let mut blob = Reality::new_at_cosmic_age(21, (-1.0, 1.0), 1.0, 0.001, 0.0);
+        blob.add_information((0.0, 0.0, 0.0), 6.0);
+        assert!((ring.total_information() - blob.total_information()).abs() < 1e-9);
+        
+        let z_axis = (0.0, 0.0, 1.0);
+        let center = blob.center_of_information();
+        assert!(center.0.abs() < 1e-9 && center.1.abs() < 1e-9 && center.2.abs() < 1e-9);
+        assert!(ring.moment_of_inertia((0.0, 0.0, 0.0), z_axis) > blob.moment_of_inertia(center, z_axis));
+    }
+    
+    #[test]
+    fn test_classify_cells_core_and_halo() {
+        // Sparse early vacuum: the broad core outgrows diffusion, the isolated halo spike does not
+        let mut reality = Reality::new_at_cosmic_age(21, (-1.0, 1.0), 1.0, 0.01, -10.0);
+        for i in -2..=2 {
+            for j in -2..=2 {
+                for k in -2..=2 {
+                    reality.add_information((i as f64 * 0.1, j as f64 * 0.1, k as f64 * 0.1), 2.0);
+                }
+            }
+        }
+        reality.add_information((0.7, 0.0, 0.0), 0.4);
+        assert!(!reality.information_at((0.7, 0.0, 0.0)).unwrap().is_conscious());
+        
+        let classes = reality.classify_cells(80);
+        assert_eq!(classes.len(), reality.as_slice().len());
+        let class_at = |position| classes[reality.position_to_index(position).unwrap()];
+        assert_eq!(class_at((0.0, 0.0, 0.0)), CellClass::SelfAmplifying);
+        assert_eq!(class_at((0.7, 0.0, 0.0)), CellClass::Decaying);
+    }
+    
+    #[test]
+    fn test_ensemble_average() {
+        let mut config = RealityConfig {
+            resolution: 12,
+            bounds: (-1.0, 1.0),
+            cosmic_age: 0.0,
+            deposits: vec![((0.0, 0.0, 0.0), 2.0)],
+            noise: 0.3,
+            ..RealityConfig::default()
+        };
+        
+        let mut single = config.build_seeded(3);
+        for _ in 0..3 {
+            single.evolve();
+        }
+        let identical = Reality::ensemble_average(&config, &[3, 3, 3], 3);
+        assert!(identical.difference(&single) < 1e-12);
+        
+        let variance = |reality: &Reality| {
+            let cells = reality.as_slice();
+            let mean = cells.iter().map(|i| i.density()).sum::<f64>() / cells.len() as f64;
+            cells.iter().map(|i| (i.density() - mean).powi(2)).sum::<f64>()
+        };
+        let seeds: Vec<u64> = (0..8).collect();
+        let averaged = Reality::ensemble_average(&config, &seeds, 3);
+        for &seed in &seeds {
+            let mut realization = config.build_seeded(seed);
+            for _ in 0..3 {
+                realization.evolve();
+            }
+            assert!(variance(&averaged) < variance(&realization));
+        }
+        
+        config.noise = 0.0;
+        assert!(config.build_seeded(1).difference(&config.build_seeded(2)) == 0.0);
+    }
+    
+    #[test]
+    fn test_isosurface_of_spherical_bump() {
+        let mut reality = Reality::new_at_cosmic_age(21, (-1.0, 1.0), 1.0, 0.001, 0.0);
+        let radius = 0.55;
+        for i in -10..=10 {
+            for j in -10..=10 {
+                for k in -10..=10 {
+                    let (x, y, z) = (i as f64 * 0.1, j as f64 * 0.1, k as f64 * 0.1);
+                    if (x * x + y * y + z * z).sqrt() <= radius {
+                        reality.add_information((x, y, z), 3.0);
+                    }
+                }
+            }
+        }
+        
+        let points = reality.consciousness_isosurface_points(2.0);
+        assert!(points.len() > 50);
+        for (x, y, z) in points {
+            let r = (x * x + y * y + z * z).sqrt();
+            assert!(r <= radius && r > radius - 0.2, "r = {}", r);
+        }
+    }
+    
+    #[test]
+    fn test_record_and_replay() {
+        let config = RealityConfig {
+            resolution: 12,
+            bounds: (-1.0, 1.0),
+            ..RealityConfig::default()
+        };
+        let mut reality = config.build();
+        reality.start_recording();
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.evolve();
+        reality.set_dt(0.002);
+        reality.add_information_batch(&[((0.4, 0.0, 0.0), 1.0), ((0.0, -0.4, 0.2), 0.5)]).unwrap();
+        reality.set_diffusion(0.5);
+        for _ in 0..5 {
+            reality.evolve();
+        }
+        let ops = reality.stop_recording();
+        assert_eq!(ops.len(), 11);
+        assert!(reality.recorded_ops().is_empty());
+        
+        let replayed = Reality::replay(&config, &ops);
+        assert_eq!(replayed.difference(&reality), 0.0);
+        assert_eq!(replayed.step(), reality.step());
+    }
+    
+    #[test]
+    fn test_comoving_density_constant_across_epochs() {
+        let comoving_at_age = |age: f64| {
+            let mut reality = Reality::new_at_cosmic_age(8, (-1.0, 1.0), 1.0, 0.001, age);
+            // Fixed 50% overdensity on top of the epoch's vacuum
+            reality.add_information((0.0, 0.0, 0.0), 0.5 * reality.vacuum_density());
+            reality.comoving_density_at((0.0, 0.0, 0.0)).unwrap()
+        };
+        
+        let early = comoving_at_age(2.0);
+        for age in [6.0, 10.0] {
+            assert!((comoving_at_age(age) - early).abs() < 1e-12);
+        }
+        assert!((early - 1.5 * VACUUM_INFORMATION).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_suggest_dt_meets_target() {
+        let mut reality = Reality::new_at_cosmic_age(16, (-1.0, 1.0), 1.0, 0.001, 0.0);
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        
+        let target = 1e-4;
+        let dt = reality.suggest_dt(target);
+        assert!(dt > 0.0 && dt <= 1.0 / 6.0);
+        
+        let measured = reality.local_error(dt);
+        assert!(measured < 1.5 * target && measured > 0.5 * target, "error {} at dt {}", measured, dt);
+    }
+    
+    #[test]
+    fn test_gradient_of_linear_ramp() {
+        // ℐ = 1 + 2x on a grid with spacing 0.25: exact for central and one-sided differences
+        let mut reality = Reality::new_at_cosmic_age(9, (0.0, 2.0), 1.0, 0.001, 0.0);
+        for idx in 0..reality.field.len() {
+            let (x, _, _) = reality.index_position(idx);
+            reality.field[idx] = Information::new(1.0 + 2.0 * x);
+        }
+        
+        for position in [(1.0, 1.0, 1.0), (0.0, 0.5, 2.0), (2.0, 0.0, 1.25)] {
+            let (gx, gy, gz) = reality.gradient(position).unwrap();
+            assert!((gx - 2.0).abs() < 1e-12);
+            assert!(gy.abs() < 1e-12 && gz.abs() < 1e-12);
+        }
+        assert!(reality.gradient((2.5, 1.0, 1.0)).is_none());
+        assert!(reality.gradient((1.0, -0.5, 1.0)).is_none());
+    }
+    
+    #[test]
+    fn test_laplacian_matches_evolution_step() {
+        let mut reality = Reality::new_at_cosmic_age(9, (0.0, 2.0), 1.0, 0.001, 0.0);
+        reality.add_information((1.0, 1.0, 1.0), 2.0);
+        
+        let dx = reality.dx();
+        let before = reality.information_at((1.25, 1.0, 1.0)).unwrap();
+        let laplacian = reality.laplacian((1.25, 1.0, 1.0)).unwrap();
+        reality.evolve();
+        let after = reality.information_at((1.25, 1.0, 1.0)).unwrap();
+        
+        let expected = before.density() + 0.001 * (dx * dx * laplacian + before.intrinsic_rate());
+        assert!((after.density() - expected).abs() < 1e-12);
+        // A single neighbour of the bump: ∇²ℐ = 2/Δx²
+        assert!((laplacian - 2.0 / (dx * dx)).abs() < 1e-9);
+        assert!(reality.laplacian((0.0, 3.0, 0.0)).is_none());
+    }
+    
+    #[test]
+    fn test_divergence_and_vorticity_of_quadratic_bowl() {
+        // ℐ = 1 + x² + y² + z²: ∇²ℐ = 6 everywhere, and central differences are exact
+        let mut reality = Reality::new_at_cosmic_age(17, (-1.0, 1.0), 2.0, 0.001, 0.0);
+        for idx in 0..reality.field.len() {
+            let (x, y, z) = reality.index_position(idx);
+            reality.field[idx] = Information::new(1.0 + x * x + y * y + z * z);
+        }
+        
+        let dx = reality.dx();
+        let position = (0.25, -0.125, 0.5);
+        let divergence = reality.divergence(position).unwrap();
+        // v = -D∇ℐ with D = diffusion·Δx²
+        assert!((divergence + 2.0 * dx * dx * 6.0).abs() < 1e-9);
+        
+        let (wx, wy, wz) = reality.vorticity(position).unwrap();
+        assert!(wx.abs() < 1e-9 && wy.abs() < 1e-9 && wz.abs() < 1e-9);
+        assert!(reality.divergence((1.5, 0.0, 0.0)).is_none());
+        assert!(reality.vorticity((0.0, 0.0, -1.5)).is_none());
+    }
+    
+    #[test]
+    fn test_snapshot_restore_branches_identically() {
+        let mut reality = Reality::new_at_cosmic_age(12, (-1.0, 1.0), 1.0, 0.005, 0.0);
+        reality.add_information((0.0, 0.0, 0.0), 2.5);
+        for _ in 0..5 {
+            reality.evolve();
+        }
+        
+        let snapshot = reality.snapshot();
+        let created = reality.information_created();
+        for _ in 0..10 {
+            reality.evolve();
+        }
+        let first_branch = reality.clone();
+        
+        reality.restore(&snapshot);
+        assert_eq!(reality.step(), 5);
+        assert_eq!(reality.information_created(), created);
+        for _ in 0..10 {
+            reality.evolve();
+        }
+        assert_eq!(reality.difference(&first_branch), 0.0);
+        assert_eq!(reality.time(), first_branch.time());
+        assert_eq!(reality.total_information(), first_branch.total_information());
+    }
+    
+    #[test]
+    fn test_conscious_fraction() {
+        let mut reality = Reality::new_at_cosmic_age(10, (-1.0, 1.0), 1.0, 0.001, -40.0);
+        assert_eq!(reality.grid_point_count(), 1000);
+        assert_eq!(reality.conscious_fraction(), 0.0);
+        
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.add_information((0.5, 0.5, 0.5), 2.0);
+        assert_eq!(reality.conscious_fraction(), 2.0 / 1000.0);
+    }
+    
+    #[test]
+    fn test_builder_validates_parameters() {
+        let reality = RealityBuilder::new().resolution(12).dt(0.002).cosmic_age(0.0).build().unwrap();
+        assert_eq!(reality.config(), RealityConfig {
+            resolution: 12,
+            dt: 0.002,
+            cosmic_age: 0.0,
+            ..RealityConfig::default()
+        });
+        
+        assert_eq!(RealityBuilder::new().resolution(1).build().err(), Some(BuildError::Resolution(1)));
+        assert_eq!(RealityBuilder::new().bounds((1.0, -1.0)).build().err(), Some(BuildError::Bounds(1.0, -1.0)));
+        assert_eq!(RealityBuilder::new().dt(0.0).build().err(), Some(BuildError::Dt(0.0)));
+        assert_eq!(RealityBuilder::new().diffusion(-0.1).build().err(), Some(BuildError::Diffusion(-0.1)));
+    }
+    
+    #[test]
+    fn test_unstable_parameters_rejected() {
+        let reality = Reality::new(8, (-1.0, 1.0), 2.0, 0.05);
+        assert!((reality.stability_number() - 0.1).abs() < 1e-12);
+        
+        let unstable = RealityBuilder::new().resolution(8).diffusion(2.0).dt(0.1).build();
+        assert_eq!(unstable.err(), Some(BuildError::Unstable(0.2)));
+        assert!(RealityBuilder::new().resolution(8).dt(MAX_STABILITY_NUMBER).build().is_ok());
+    }
+    
+    #[test]
+    #[should_panic(expected = "exceeds the explicit limit")]
+    fn test_set_dt_rejects_unstable_dt() {
+        let mut reality = Reality::new(8, (-1.0, 1.0), 2.0, 0.05);
+        reality.set_dt(0.1);
+    }
+    
+    #[test]
+    #[should_panic(expected = "dt must be positive")]
+    fn test_set_dt_rejects_non_positive_dt() {
+        let mut reality = Reality::new(8, (-1.0, 1.0), 2.0, 0.05);
+        reality.set_dt(-0.01);
+    }
+    
+    #[test]
+    fn test_rk4_tracks_total_information_closer_than_euler() {
+        let gaussian_seed = |dt: f64| {
+            let mut reality = Reality::new_at_cosmic_age(10, (-1.0, 1.0), 0.5, dt, -40.0);
+            for idx in 0..reality.field.len() {
+                let (x, y, z) = reality.index_position(idx);
+                reality.field[idx] = Information::new(2.0 * (-(x * x + y * y + z * z) / 0.2).exp());
+            }
+            reality.recount();
+            reality
+        };
+        
+        // 1000 steps of dt = 0.004, against RK4 at a quarter of the step
+        let mut euler = gaussian_seed(0.004);
+        let mut rk4 = gaussian_seed(0.004);
+        let mut reference = gaussian_seed(0.001);
+        let (mut euler_drift, mut rk4_drift) = (0.0_f64, 0.0_f64);
+        for _ in 0..1000 {
+            euler.evolve();
+            rk4.evolve_rk4();
+            for _ in 0..4 {
+                reference.evolve_rk4();
+            }
+            let exact = reference.total_information();
+            euler_drift = euler_drift.max((euler.total_information() - exact).abs());
+            rk4_drift = rk4_drift.max((rk4.total_information() - exact).abs());
+        }
+        
+        assert!(rk4_drift < 1e-3 * euler_drift, "RK4 drift {} vs Euler {}", rk4_drift, euler_drift);
+    }
+    
+    #[test]
+    fn test_evolve_adaptive_keeps_error_and_respects_limits() {
+        let mut reality = Reality::new_at_cosmic_age(12, (-1.0, 1.0), 1.0, 0.1, 0.0);
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        reality.set_dt_limits(1e-4, 0.05);
+        
+        let tol = 1e-4;
+        let mut time = 0.0;
+        for _ in 0..5 {
+            let before = reality.clone();
+            let dt = reality.evolve_adaptive(tol);
+            assert!((1e-4..=0.05).contains(&dt));
+            assert!(before.local_error(dt) <= tol);
+            time += dt;
+        }
+        assert!((reality.time() - time).abs() < 1e-12);
+        assert_eq!(reality.step(), 5);
+        
+        // A loose tolerance grows dt up to the clamp
+        for _ in 0..10 {
+            reality.evolve_adaptive(1.0);
+        }
+        assert_eq!(reality.dt, 0.05);
+    }
+    
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_update_matches_sequential() {
+        let mut reality = Reality::new_at_cosmic_age(20, (-1.0, 1.0), 0.8, 0.01, 0.0);
+        reality.add_noise(0.5, 7);
+        reality.add_information((0.2, -0.1, 0.0), 3.0);
+        
+        for _ in 0..20 {
+            let parallel = reality.rates_parallel(&reality.field);
+            let sequential = reality.rates_sequential(&reality.field);
+            assert_eq!(parallel, sequential);
+            assert_eq!(
+                reality.euler_chunks_parallel(&parallel, reality.dt, None),
+                reality.euler_chunks_sequential(&sequential, reality.dt, None)
+            );
+            reality.evolve();
+        }
+    }
+    
+    #[test]
+    fn test_iter_cells_and_mut_keep_totals() {
+        let mut reality = Reality::new_at_cosmic_age(6, (0.0, 1.0), 1.0, 0.01, -40.0);
+        let cells: Vec<_> = reality.iter_cells().collect();
+        assert_eq!(cells.len(), 216);
+        let (i, j, k, position, _) = cells[1 + 2 * 6 + 3 * 36];
+        assert_eq!((i, j, k), (1, 2, 3));
+        assert_eq!(position, reality.cell_position(1, 2, 3));
+        
+        // Raise the x = 1 face to 2 bits
+        for (i, _, _, _, info) in reality.iter_cells_mut() {
+            if i == 5 {
+                *info = Information::new(2.0);
+            }
+        }
+        assert_eq!(reality.conscious_count(), 36);
+        assert_eq!(reality.conscious_fraction(), 36.0 / 216.0);
+        
+        // The next mutation rebuilds the running totals from the edited field
+        reality.add_information((0.0, 0.0, 0.0), 1.0);
+        let (total, conscious) = reality.scan_totals();
+        assert!((reality.total_information() - total).abs() < 1e-12);
+        assert_eq!(reality.conscious_count(), conscious);
+        assert_eq!(conscious, 37);
+    }
+    
+    #[test]
+    fn test_zero_axis_diffusion_blocks_spreading() {
+        let evolved = |amplitude: f64, steps: usize| {
+            let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, 0.01, -40.0);
+            reality.set_diffusion_xyz(1.0, 0.5, 0.0);
+            reality.add_information((0.0, 0.0, 0.0), amplitude);
+            for _ in 0..steps {
+                reality.evolve();
+            }
+            reality
+        };
+        let at = |reality: &Reality, position| reality.information_at(position).unwrap().density();
+        
+        let (bump, vacuum) = (evolved(3.0, 20), evolved(0.0, 20));
+        assert!(at(&bump, (0.25, 0.0, 0.0)) > at(&bump, (0.0, 0.25, 0.0)));
+        assert!(at(&bump, (0.0, 0.25, 0.0)) > at(&vacuum, (0.0, 0.25, 0.0)));
+        // The z = 0.25 plane never sees the bump
+        assert_eq!(at(&bump, (0.0, 0.0, 0.25)), at(&vacuum, (0.0, 0.0, 0.25)));
+        assert!((bump.stability_number() - 0.005).abs() < 1e-12);
+        
+        // Back to isotropic: z spreads again
+        let (mut bump, mut vacuum) = (bump, vacuum);
+        bump.set_diffusion(1.0);
+        vacuum.set_diffusion(1.0);
+        bump.evolve();
+        vacuum.evolve();
+        assert!(at(&bump, (0.0, 0.0, 0.25)) > at(&vacuum, (0.0, 0.0, 0.25)));
+    }
+    
+    #[test]
+    fn test_2d_gaussian_diffuses_radially_symmetric() {
+        let mut reality = Reality::new_2d(33, (-2.0, 2.0), 1.0, 0.05);
+        assert_eq!(reality.grid_point_count(), 33 * 33);
+        for (_, _, _, (x, y, z), info) in reality.iter_cells_mut() {
+            assert_eq!(z, 0.0);
+            *info = Information::new(3.0 * (-(x * x + y * y) / 0.5).exp());
+        }
+        for _ in 0..30 {
+            reality.evolve();
+        }
+        let at = |x: f64, y: f64| reality.information_at((x, y, 0.0)).unwrap().density();
+        
+        // Grid radius 5: on the axes and at the (3, 4) lattice points
+        let on_axis = at(0.625, 0.0);
+        for density in [at(-0.625, 0.0), at(0.0, 0.625), at(0.0, -0.625)] {
+            assert!((density - on_axis).abs() < 1e-12 * on_axis);
+        }
+        for density in [at(0.375, 0.5), at(-0.5, 0.375), at(0.5, -0.375)] {
+            assert!((density - on_axis).abs() < 0.02 * on_axis, "{} vs {}", density, on_axis);
+        }
+        // Grew outward, and z is ignored
+        assert!(on_axis > 3.0 * (-(0.625_f64 * 0.625) / 0.5).exp());
+        assert_eq!(at(0.625, 0.0), reality.information_at((0.625, 0.0, 1.7)).unwrap().density());
+        assert!(reality.gradient((0.625, 0.0, 0.0)).unwrap().2 == 0.0);
+    }
+    
+    #[test]
+    fn test_history_records_every_nth_step() {
+        let mut reality = Reality::new(8, (-1.0, 1.0), 1.0, 0.01);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.evolve();
+        reality.enable_history(3);
+        for _ in 0..7 {
+            reality.evolve();
+        }
+        reality.evolve_rk4();
+        
+        let steps: Vec<u64> = reality.history().iter().map(|frame| frame.step).collect();
+        assert_eq!(steps, vec![3, 6, 9]);
+        let last = reality.history()[2];
+        assert_eq!(last.time, reality.time());
+        assert_eq!(last.total_information, reality.total_information());
+        assert_eq!(last.conscious_count, reality.conscious_count());
+        let peak = reality.iter_cells().map(|(_, _, _, _, density)| density).fold(0.0, f64::max);
+        assert_eq!(last.max_consciousness, peak);
+        
+        reality.enable_history(0);
+        reality.evolve();
+        assert!(reality.history().is_empty());
+    }
+    
+    #[test]
+    fn test_evolve_with_visits_each_step_and_breaks() {
+        let mut reality = Reality::new(6, (-1.0, 1.0), 1.0, 0.01);
+        let mut seen = Vec::new();
+        let taken = reality.evolve_with(5, |field, index| {
+            seen.push((index, field.step()));
+            ControlFlow::Continue(())
+        });
+        assert_eq!(taken, 5);
+        assert_eq!(seen, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        
+        let taken = reality.evolve_with(100, |field, _| {
+            if field.step() >= 8 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        });
+        assert_eq!(taken, 3);
+        assert_eq!(reality.step(), 8);
+    }
+    
+    #[test]
+    fn test_add_noise_is_seeded_and_clamped() {
+        let noisy = |seed: u64| {
+            let mut reality = Reality::new_at_cosmic_age(6, (-1.0, 1.0), 1.0, 0.01, -40.0);
+            reality.add_noise(0.5, seed);
+            reality
+        };
+        assert_eq!(noisy(1).difference(&noisy(1)), 0.0);
+        assert!(noisy(1).difference(&noisy(2)) > 0.0);
+        // Near-zero vacuum: about half the draws clamp at zero
+        let reality = noisy(1);
+        assert!(reality.as_slice().iter().all(|info| (0.0..=0.5 + 1e-3).contains(&info.density())));
+        assert!(reality.as_slice().iter().any(|info| info.density() == 0.0));
+        
+        let mut recorded = Reality::new(6, (-1.0, 1.0), 1.0, 0.01);
+        recorded.start_recording();
+        recorded.add_noise(0.2, 9);
+        recorded.evolve();
+        let replayed = Reality::replay(&recorded.config(), recorded.recorded_ops());
+        assert_eq!(replayed.difference(&recorded), 0.0);
+    }
+    
+    #[test]
+    fn test_argmax_consciousness_finds_moving_peak() {
+        let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, 0.01, -40.0);
+        reality.add_information((0.5, -0.25, 0.75), 2.0);
+        let (position, density) = reality.argmax_consciousness().unwrap();
+        assert_eq!(position, (0.5, -0.25, 0.75));
+        assert_eq!(density, reality.information_at(position).unwrap().density());
+        
+        reality.add_information((-0.5, 0.0, 0.0), 3.0);
+        assert_eq!(reality.argmax_consciousness().unwrap().0, (-0.5, 0.0, 0.0));
+    }
+    
+    #[test]
+    fn test_information_in_sphere_and_box() {
+        // Δx = 0.25, cell volume 1/64
+        let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, 0.01, -40.0);
+        let vacuum = reality.vacuum_density();
+        reality.add_information((0.5, 0.0, 0.0), 2.0);
+        
+        // Seven cells: the center and its six neighbours
+        let sphere = reality.information_in_sphere((0.5, 0.0, 0.0), 0.3);
+        assert!((sphere - (2.0 + 7.0 * vacuum) / 64.0).abs() < 1e-12);
+        assert!((reality.information_in_sphere((-0.5, 0.0, 0.0), 0.3) - 7.0 * vacuum / 64.0).abs() < 1e-12);
+        
+        // Faces included: 3 × 2 × 1 cells
+        let cells = reality.information_in_box((0.25, -0.25, 0.0), (0.75, 0.0, 0.0));
+        assert!((cells - (2.0 + 6.0 * vacuum) / 64.0).abs() < 1e-12);
+        let everything = reality.information_in_box((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+        assert!((everything - reality.total_information() / 64.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_local_maxima_are_strict_spatial_peaks() {
+        let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, 0.01, -40.0);
+        assert!(reality.local_maxima(0.0).is_empty());
+        
+        reality.add_information((-0.5, 0.0, 0.0), 2.0);
+        reality.add_information((0.5, 0.25, 0.0), 1.0);
+        // A two-cell plateau is not a peak
+        reality.add_information((0.0, -0.75, 0.5), 1.5);
+        reality.add_information((0.25, -0.75, 0.5), 1.5);
+        // A face cell counts against its in-grid neighbours
+        reality.add_information((1.0, 1.0, 1.0), 0.5);
+        
+        let peaks = reality.local_maxima(0.1);
+        let positions: Vec<_> = peaks.iter().map(|&(position, _)| position).collect();
+        assert_eq!(positions, vec![(-0.5, 0.0, 0.0), (0.5, 0.25, 0.0), (1.0, 1.0, 1.0)]);
+        assert_eq!(reality.local_maxima(0.9).len(), 2);
+        assert_eq!(peaks[0].1, reality.information_at((-0.5, 0.0, 0.0)).unwrap().density());
+    }
+    
+    #[test]
+    fn test_shannon_entropy_uniform_and_spike() {
+        let mut reality = Reality::new_at_cosmic_age(6, (-1.0, 1.0), 1.0, 0.01, -40.0);
+        assert_eq!(reality.shannon_entropy(), 0.0);
+        
+        reality.add_information((0.2, 0.2, 0.2), 2.0);
+        assert!(reality.shannon_entropy().abs() < 1e-12);
+        reality.evolve();
+        assert!(reality.shannon_entropy() > 0.0);
+        
+        for (_, _, _, _, info) in reality.iter_cells_mut() {
+            *info = Information::new(1.0);
+        }
+        let cells = 216.0_f64;
+        assert!((reality.shannon_entropy() - cells.ln()).abs() < 1e-9);
+        assert!((reality.spatial_entropy_bits() - cells.log2()).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_forward_backward_round_trip_is_nearly_reversible() {
+        let mut reality = Reality::new_at_cosmic_age(12, (-1.5, 1.5), 1.0, 0.01, 0.0);
+        for (_, _, _, (x, y, z), info) in reality.iter_cells_mut() {
+            *info = Information::new(1.0 + (-(x * x + y * y + z * z) / 0.5).exp());
+        }
+        let start = reality.clone();
+        
+        for _ in 0..10 {
+            reality.evolve();
+        }
+        for _ in 0..10 {
+            reality.evolve_backward();
+        }
+        assert!(reality.time().abs() < 1e-12);
+        assert_eq!(reality.step(), 20);
+        
+        let residue = reality.as_slice().iter().zip(start.as_slice())
+            .map(|(a, b)| (a.density() - b.density()).abs())
+            .fold(0.0, f64::max);
+        assert!(residue > 0.0, "the nonlinear step is not exactly reversible");
+        // 10 pairs × dt² = 1e-3, times a rate-Jacobian product of order one
+        assert!(residue < 5e-3, "residue {}", residue);
+    }
+    
+    #[test]
+    fn test_information_balance_closes() {
+        let mut reality = Reality::new_at_cosmic_age(10, (-1.0, 1.0), 1.0, 0.02, 0.0);
+        assert_eq!(reality.information_balance(), InfoBalance::default());
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        // A bump next to the z face drives flux into it
+        reality.add_information((0.0, 0.0, 0.777), 2.0);
+        
+        for _ in 0..5 {
+            let before = reality.total_information();
+            reality.evolve();
+            let balance = reality.information_balance();
+            assert!((balance.change - (reality.total_information() - before)).abs() < 1e-12);
+            assert!(balance.residual().abs() < 1e-9, "residual {}", balance.residual());
+            assert!(balance.source > 0.0 && balance.sink > 0.0);
+            assert!(balance.boundary_flux > 0.0);
+        }
+        
+        reality.evolve_backward();
+        assert!(reality.information_balance().residual().abs() < 1e-9);
+        assert!(reality.information_balance().source < 0.0);
+    }
+    
+    #[test]
+    fn test_higher_max_information_allows_denser_peaks() {
+        let saturate = |i_max: Option<f64>| {
+            let mut reality = Reality::new(6, (-1.0, 1.0), 0.1, 0.05);
+            if let Some(i_max) = i_max {
+                reality.set_max_information(i_max);
+            }
+            for _ in 0..600 {
+                reality.evolve();
+            }
+            let peak = reality.argmax_consciousness().unwrap().1;
+            reality.evolve();
+            (peak, reality.argmax_consciousness().unwrap().1)
+        };
+        
+        let (default_peak, _) = saturate(None);
+        let (peak, next) = saturate(Some(32.0));
+        assert!(default_peak <= MAX_INFORMATION);
+        assert!(peak > MAX_INFORMATION && peak <= 32.0);
+        assert!((next - peak).abs() < 1e-6, "stable peak");
+        
+        // Lowering the ceiling clamps the field
+        let mut reality = Reality::new(4, (-1.0, 1.0), 1.0, 0.01);
+        reality.set_max_information(2.0);
+        assert_eq!(reality.max_information(), 2.0);
+        assert!(reality.as_slice().iter().all(|info| info.density() == 2.0));
+        assert_eq!(reality.total_information(), 128.0);
+    }
+    
+    #[test]
+    fn test_custom_uncertainty_fn_drives_decay() {
+        let mut reality = Reality::new_at_cosmic_age(5, (-1.0, 1.0), 1.0, 0.01, 0.0);
+        let mut pure_logistic = reality.clone();
+        pure_logistic.set_uncertainty_fn(Box::new(|_| 0.0));
+        let mut strong = reality.clone();
+        strong.set_uncertainty_fn(Box::new(|density| 2.0 / (1.0 + density)));
+        
+        let vacuum = reality.vacuum_density();
+        for field in [&mut reality, &mut pure_logistic, &mut strong] {
+            field.evolve();
+        }
+        let center = |field: &Reality| field.information_at((0.0, 0.0, 0.0)).unwrap().density();
+        
+        // Uniform field: no diffusion, only the intrinsic rate
+        let logistic = vacuum * (1.0 - vacuum / MAX_INFORMATION);
+        assert!((center(&pure_logistic) - (vacuum + 0.01 * logistic)).abs() < 1e-12);
+        assert!((center(&reality) - (vacuum + 0.01 * Information(vacuum).intrinsic_rate())).abs() < 1e-12);
+        // ε = 2/(1+ℐ) at ℐ = 1/√2 outweighs the growth
+        assert!(center(&strong) < vacuum);
+        assert!(strong.information_balance().residual().abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_current_and_divergence_fields_match_pointwise() {
+        let mut reality = Reality::new_at_cosmic_age(7, (-1.0, 1.0), 0.8, 0.01, 0.0);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        reality.add_information((-1.0 / 3.0, 1.0 / 3.0, 0.0), 1.0);
+        reality.evolve();
+        
+        let current = reality.current_field();
+        let divergence = reality.divergence_field();
+        assert_eq!(current.len(), reality.grid_point_count());
+        for ((position, flux), (same, div)) in current.iter().zip(&divergence) {
+            assert_eq!(position, same);
+            let (gx, gy, gz) = reality.gradient(*position).unwrap();
+            let d = 0.8 * reality.dx().powi(2);
+            assert!((flux.0 + d * gx).abs() < 1e-12 && (flux.1 + d * gy).abs() < 1e-12 && (flux.2 + d * gz).abs() < 1e-12);
+            assert!((div - reality.divergence(*position).unwrap()).abs() < 1e-12);
+        }
+        
+        // A uniform steady state carries no current
+        let vacuum = Reality::new(5, (-1.0, 1.0), 1.0, 0.01);
+        assert!(vacuum.divergence_field().iter().all(|&(_, div)| div == 0.0));
+    }
+    
+    #[test]
+    fn test_find_critical_threshold_brackets_integration_threshold() {
+        // Faces never evolve, so keep them a minority of the cells
+        let uniform = |density: f64| {
+            let mut reality = Reality::new_at_cosmic_age(16, (-1.0, 1.0), 1.0, 0.01, -40.0);
+            for (_, _, _, _, info) in reality.iter_cells_mut() {
+                *info = Information::new(density);
+            }
+            reality
+        };
+        assert_eq!(uniform(0.8).order_parameter(), 1.0);
+        assert_eq!(uniform(0.6).order_parameter(), 0.0);
+        
+        let densities: Vec<f64> = (0..26).map(|i| 0.5 + 0.02 * i as f64).collect();
+        let critical = find_critical_threshold_with_densities(uniform, &densities, 0);
+        assert!((critical - INTEGRATION_THRESHOLD).abs() <= 0.01);
+        
+        // Growth lets lower initial densities cross the threshold in time
+        let evolved = find_critical_threshold_with_densities(uniform, &densities, 20);
+        assert!(evolved < critical);
+        
+        // The default sweep covers the whole density range
+        let default = find_critical_threshold(uniform, 0);
+        assert!((default - INTEGRATION_THRESHOLD).abs() <= 0.5 * CRITICAL_SWEEP_STEP);
+    }
+    
+    #[test]
+    fn test_add_information_batch_matches_sequential_deposits() {
+        let deposits = [
+            ((0.0, 0.0, 0.0), 3.0),
+            ((0.5, -0.5, 0.0), 1.0),
+            ((0.0, 0.0, 0.0), -2.5),
+            ((0.02, 0.0, 0.0), 4.0),
+            ((-1.0, 1.0, 1.0), 0.75),
+        ];
+        let mut batch = Reality::new_at_cosmic_age(5, (-1.0, 1.0), 0.1, 0.01, 0.0);
+        batch.set_max_information(5.0);
+        let mut sequential = batch.clone();
+        batch.add_information_batch(&deposits).unwrap();
+        for &(position, amplitude) in &deposits {
+            sequential.add_information(position, amplitude);
+        }
+        assert_eq!(batch.as_slice(), sequential.as_slice());
+        assert!((batch.total_information() - sequential.total_information()).abs() < 1e-12);
+        
+        let before = batch.snapshot();
+        let error = batch.add_information_batch(&[((0.0, 0.0, 0.0), 1.0), ((0.0, 1.8, 0.0), 1.0)]).err().unwrap();
+        assert_eq!(error.position, (0.0, 1.8, 0.0));
+        assert_eq!(batch.snapshot(), before);
+    }
+    
+    #[test]
+    fn test_is_finite_max_density_and_evolve_checked() {
+        let mut reality = Reality::new(6, (-1.0, 1.0), 0.1, 0.01);
+        reality.add_information((0.2, 0.2, 0.2), 2.0);
+        assert!(reality.is_finite());
+        assert_eq!(reality.max_density(), reality.argmax_consciousness().unwrap().1);
+        assert_eq!(reality.evolve_checked(), Ok(()));
+        
+        for (i, j, k, _, info) in reality.iter_cells_mut() {
+            if (i, j, k) == (2, 3, 2) {
+                *info = Information(f64::NAN);
+            }
+        }
+        assert!(!reality.is_finite());
+        let error = reality.evolve_checked().err().unwrap();
+        assert!(matches!(error, EvolveError::Diverged { density, .. } if density.is_nan()));
+        
+        reality.set_clamping(true);
+        assert_eq!(reality.evolve_checked(), Ok(()));
+        assert!(reality.is_finite());
+    }
+    
+    #[test]
+    fn test_advance_cosmic_time_raises_vacuum_uniformly() {
+        let mut reality = Reality::new_at_cosmic_age(6, (-1.0, 1.0), 0.1, 0.01, 2.0);
+        reality.add_information((0.0, 0.0, 0.0), 1.5);
+        let created = reality.information_created();
+        reality.start_recording();
+        reality.advance_cosmic_time(3.0);
+        
+        assert_eq!(reality.cosmic_age(), 5.0);
+        assert!((reality.vacuum_density() - vacuum_at_cosmic_time(5.0)).abs() < 1e-12);
+        assert!((reality.information_at((1.0, 1.0, 1.0)).unwrap().density() - reality.vacuum_density()).abs() < 1e-12);
+        assert!((reality.information_created() - created).abs() < 1e-9);
+        
+        let replayed = Reality::replay(&Reality::new_at_cosmic_age(6, (-1.0, 1.0), 0.1, 0.01, 2.0).config(), &[
+            Op::AddInformation { position: (0.0, 0.0, 0.0), amplitude: 1.5 },
+            reality.stop_recording()[0],
+        ]);
+        assert_eq!(replayed.difference(&reality), 0.0);
+    }
+    
+    #[test]
+    fn test_information_at_interpolated() {
+        let mut reality = Reality::new(5, (0.0, 1.0), 0.1, 0.01);
+        reality.add_linear_gradient(Axis::X, 2.0, 6.0, (0.0, 1.0));
+        let exact = |r: &Reality, pos| r.information_at(pos).unwrap().density();
+        
+        assert!((reality.information_at_interpolated((0.25, 0.5, 0.5)).unwrap() - exact(&reality, (0.25, 0.5, 0.5))).abs() < 1e-12);
+        // Linear between cell centers where the nearest-cell lookup steps
+        let scan: Vec<f64> = (0..=10).map(|n| reality.information_at_interpolated((0.25 + 0.025 * n as f64, 0.5, 0.5)).unwrap()).collect();
+        let (low, high) = (exact(&reality, (0.25, 0.5, 0.5)), exact(&reality, (0.5, 0.5, 0.5)));
+        for (n, density) in scan.iter().enumerate() {
+            assert!((density - (low + (high - low) * n as f64 / 10.0)).abs() < 1e-9);
+        }
+        
+        assert_eq!(reality.information_at_interpolated((1.2, 0.5, 0.5)), None);
+        assert_eq!(reality.information_at_interpolated((0.5, f64::NAN, 0.5)), None);
+        let plane = Reality::new_2d(5, (0.0, 1.0), 0.1, 0.01);
+        assert!((plane.information_at_interpolated((0.3, 0.6, 42.0)).unwrap() - plane.vacuum_density()).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_downsample_and_upsample() {
+        let mut reality = Reality::new(9, (-1.0, 1.0), 0.1, 0.01);
+        reality.add_linear_gradient(Axis::X, 2.0, 6.0, (-1.0, 1.0));
+        reality.evolve();
+        
+        let fine = reality.upsample(2);
+        assert_eq!(fine.shape(), (17, 17, 17));
+        assert_eq!((fine.bounds(), fine.time(), fine.step()), (reality.bounds(), reality.time(), reality.step()));
+        assert!((fine.diffusion() * fine.dx().powi(2) - reality.diffusion() * reality.dx().powi(2)).abs() < 1e-12);
+        assert!(fine.stability_number() <= MAX_STABILITY_NUMBER);
+        for (_, _, _, position, density) in reality.iter_cells() {
+            assert!((fine.information_at(position).unwrap().density() - density).abs() < 1e-12);
+        }
+        // Midway between cells along the ramp: the mean of the neighbours
+        let midpoint = fine.information_at((0.125, 0.0, 0.0)).unwrap().density();
+        let neighbours = [(0.0, 0.0, 0.0), (0.25, 0.0, 0.0)].map(|p| reality.information_at(p).unwrap().density());
+        assert!((midpoint - 0.5 * (neighbours[0] + neighbours[1])).abs() < 1e-12);
+        
+        let coarse = fine.downsample(2);
+        assert_eq!(coarse.shape(), reality.shape());
+        // Block averages track the original cells to within the ramp's curvature
+        for (_, _, _, position, density) in reality.iter_cells() {
+            assert!((coarse.information_at(position).unwrap().density() - density).abs() < 0.1);
+        }
+        
+        let uniform = Reality::new_2d(7, (0.0, 3.0), 0.1, 0.01).downsample(3);
+        assert_eq!(uniform.shape(), (3, 3, 1));
+        assert!(uniform.as_slice().iter().all(|info| (info.density() - uniform.vacuum_density()).abs() < 1e-12));
+    }
+    
+    #[test]
+    fn test_effective_temperature_is_mean_excess() {
+        let mut reality = Reality::new(5, (-1.0, 1.0), 0.1, 0.01);
+        assert!(reality.effective_temperature().abs() < 1e-12);
+        reality.add_information((0.0, 0.0, 0.0), 1.25);
+        assert!((reality.effective_temperature() - 1.25 / 125.0).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_variance_and_susceptibility() {
+        let mut reality = Reality::new(4, (-1.0, 1.0), 1.0, 0.01);
+        assert!(reality.information_variance().abs() < 1e-9);
+        assert!(reality.susceptibility().abs() < 1e-9);
+        
+        // Half the cells 1 above vacuum, half 3 above: temperature 2, variance 1
+        let vacuum = reality.vacuum_density();
+        for (idx, (_, _, _, _, info)) in reality.iter_cells_mut().enumerate() {
+            *info = Information::new(vacuum + if idx % 2 == 0 { 1.0 } else { 3.0 });
+        }
+        assert!((reality.information_variance() - 1.0).abs() < 1e-12);
+        assert!((reality.effective_temperature() - 2.0).abs() < 1e-12);
+        assert!((reality.susceptibility() - 0.5).abs() < 1e-12);
+        
+        let empty = Reality::new_at_cosmic_age(3, (0.0, 1.0), 1.0, 0.01, -1e4);
+        assert_eq!(empty.susceptibility(), 0.0);
+        // A field depleted below vacuum has no temperature scale
+        let mut depleted = Reality::new(4, (-1.0, 1.0), 1.0, 0.01);
+        for (idx, (_, _, _, _, info)) in depleted.iter_cells_mut().enumerate() {
+            *info = Information::new(if idx % 2 == 0 { 0.0 } else { 0.5 });
+        }
+        assert!(depleted.effective_temperature() < 0.0);
+        assert_eq!(depleted.susceptibility(), 0.0);
+    }
+    
+    #[test]
+    fn test_coupling_pulls_fields_together() {
+        let mut system = Reality::new(6, (-1.0, 1.0), 1.0, 0.01);
+        let mut bath = system.clone();
+        system.add_information((0.2, 0.2, 0.2), 2.0);
+        bath.add_information((-0.6, 0.2, 0.2), 2.0);
+        
+        let mut uncoupled = system.clone();
+        for _ in 0..10 {
+            system.evolve();
+            uncoupled.evolve();
+            bath.evolve();
+            let before = system.clone();
+            system.couple(&bath, 5.0);
+            bath.couple(&before, 5.0);
+        }
+        assert!(system.difference(&bath) < uncoupled.difference(&bath));
+        
+        // One step of strength 1/dt copies the other field
+        let mut copy = system.clone();
+        copy.couple(&bath, 100.0);
+        assert!(copy.difference(&bath) < 1e-12);
+        assert!((copy.total_information() - bath.total_information()).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_add_gaussian_is_normalized_and_smooth() {
+        let mut reality = Reality::new_at_cosmic_age(17, (-2.0, 2.0), 1.0, 0.01, 0.0);
+        let before = reality.total_information();
+        reality.add_gaussian((0.1, 0.0, -0.2), 3.0, 0.5);
+        assert!((reality.total_information() - before - 3.0).abs() < 1e-9);
+        
+        // Peak at the nearest cell, symmetric about the center's grid line
+        let vacuum = reality.vacuum_density();
+        let excess = |position| reality.information_at(position).unwrap().density() - vacuum;
+        assert_eq!(reality.argmax_consciousness().unwrap().0, (0.0, 0.0, -0.25));
+        assert!((excess((0.0, 0.5, -0.25)) - excess((0.0, -0.5, -0.25))).abs() < 1e-15);
+        assert!(excess((0.0, 0.5, -0.25)) < excess((0.0, 0.25, -0.25)));
+        assert_eq!(excess((0.0, 1.75, -0.25)), 0.0);
+        
+        // A sigma far below the spacing degenerates to add_information
+        let mut point = Reality::new_at_cosmic_age(5, (-1.0, 1.0), 1.0, 0.01, 0.0);
+        let mut gaussian = point.clone();
+        point.add_information((0.4, 0.0, 0.0), 1.0);
+        gaussian.add_gaussian((0.4, 0.0, 0.0), 1.0, 1e-3);
+        assert_eq!(point.difference(&gaussian), 0.0);
+    }
+    
+    #[test]
+    fn test_add_linear_gradient_sets_exact_ramp() {
+        let mut reality = Reality::new_at_cosmic_age(9, (-2.0, 2.0), 1.0, 0.01, 0.0);
+        let vacuum = reality.vacuum_density();
+        reality.add_linear_gradient(Axis::X, 1.0, 3.0, (-1.0, 1.0));
+        
+        for (x, expected) in [(-2.0, vacuum), (-1.0, 1.0), (0.0, 2.0), (0.5, 2.5), (1.0, 3.0), (1.5, vacuum)] {
+            for position in [(x, 0.0, 0.0), (x, -2.0, 1.5)] {
+                let density = reality.information_at(position).unwrap().density();
+                assert!((density - expected).abs() < 1e-12, "{:?}: {}", position, density);
+            }
+        }
+        
+        // A reversed region runs the ramp the other way
+        reality.add_linear_gradient(Axis::X, 1.0, 3.0, (1.0, -1.0));
+        assert!((reality.information_at((-1.0, 0.0, 0.0)).unwrap().density() - 3.0).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_contains_and_nearest_cell() {
+        let reality = Reality::new(5, (-1.0, 1.0), 1.0, 0.01);
+        assert!(reality.contains((0.0, 0.0, 0.0)));
+        assert!(reality.contains((1.2, -1.2, 0.0)), "within half a cell");
+        assert!(!reality.contains((1.3, 0.0, 0.0)));
+        assert!(!reality.contains((-1.3, 0.0, 0.0)), "must not saturate onto the face");
+        assert!(!reality.contains((-100.0, 0.0, 0.0)));
+        assert!(reality.information_at((-100.0, 0.0, 0.0)).is_none());
+        
+        // Derivatives are defined exactly where `contains` is
+        for position in [(1.2, -1.2, 0.0), (0.0, 0.0, -1.2), (1.3, 0.0, 0.0), (0.0, -1.3, 0.0)] {
+            let inside = reality.contains(position);
+            assert_eq!(reality.gradient(position).is_some(), inside);
+            assert_eq!(reality.laplacian(position).is_some(), inside);
+            assert_eq!(reality.divergence(position).is_some(), inside);
+            assert_eq!(reality.vorticity(position).is_some(), inside);
+            assert_eq!(reality.electric_field(position).is_some(), inside);
+            assert_eq!(reality.magnetic_field(position).is_some(), inside);
+        }
+        
+        assert_eq!(reality.nearest_cell((0.0, 0.0, 0.0)), (2, 2, 2));
+        assert_eq!(reality.nearest_cell((0.3, -0.8, 0.7)), (3, 0, 3));
+        assert_eq!(reality.nearest_cell((-100.0, 100.0, 0.0)), (0, 4, 2));
+        
+        let plane = Reality::new_2d(5, (-1.0, 1.0), 1.0, 0.01);
+        assert!(plane.contains((0.5, 0.5, 42.0)));
+        assert_eq!(plane.nearest_cell((0.5, 0.5, 42.0)), (3, 3, 0));
+    }
+    
+    #[test]
+    fn test_geometry_accessors() {
+        let mut reality = Reality::new(21, (-1.0, 3.0), 0.7, 0.004);
+        assert_eq!(reality.dx(), 0.2);
+        assert_eq!(reality.resolution(), 21);
+        assert_eq!(reality.bounds(), (-1.0, 3.0));
+        assert_eq!(reality.dt(), 0.004);
+        assert_eq!(reality.diffusion(), 0.7);
+        
+        reality.set_diffusion_xyz(0.3, 0.6, 0.9);
+        assert!((reality.diffusion() - 0.6).abs() < 1e-15);
+        assert_eq!(reality.config().resolution, reality.resolution());
+    }
+    
+    #[test]
+    fn test_remove_information_stops_at_vacuum() {
+        let mut reality = Reality::new_at_cosmic_age(5, (-1.0, 1.0), 1.0, 0.01, 0.0);
+        let vacuum = reality.vacuum_density();
+        let density = |reality: &Reality| reality.information_at((0.0, 0.0, 0.0)).unwrap().density();
+        
+        reality.add_information((0.0, 0.0, 0.0), 1.0);
+        reality.remove_information((0.0, 0.0, 0.0), 0.25);
+        assert!((density(&reality) - vacuum - 0.75).abs() < 1e-12);
+        reality.remove_information((0.0, 0.0, 0.0), 10.0);
+        assert_eq!(density(&reality), vacuum);
+        
+        // Below vacuum on purpose: removal leaves it alone
+        reality.set_density((0.0, 0.0, 0.0), 0.1);
+        reality.remove_information((0.0, 0.0, 0.0), 0.05);
+        assert_eq!(density(&reality), 0.1);
+        assert!((reality.total_information() - (vacuum * 124.0 + 0.1)).abs() < 1e-9);
+    }
+    
+    #[test]
+    #[should_panic(expected = "set_density requires")]
+    fn test_set_density_rejects_values_above_max_information() {
+        let mut reality = Reality::new(5, (-1.0, 1.0), 1.0, 0.01);
+        let max_information = reality.max_information();
+        reality.set_density((0.0, 0.0, 0.0), max_information * 2.0);
+    }
+    
+    #[test]
+    fn test_clamping_repairs_nonphysical_cells() {
+        let mut reality = Reality::new(6, (-1.0, 1.0), 1.0, 0.01);
+        reality.evolve();
+        assert!(!reality.has_nonphysical_values());
+        
+        // Faces are never updated, so a bad face value survives plain steps
+        for (i, j, k, _, info) in reality.iter_cells_mut() {
+            if (i, j, k) == (0, 0, 0) {
+                *info = Information(-1.0);
+            } else if (i, j, k) == (5, 5, 5) {
+                *info = Information(f64::NAN);
+            }
+        }
+        assert!(reality.has_nonphysical_values());
+        reality.evolve();
+        assert!(reality.has_nonphysical_values());
+        
+        reality.set_clamping(true);
+        reality.evolve();
+        assert!(!reality.has_nonphysical_values());
+        assert_eq!(reality.information_at((-1.0, -1.0, -1.0)).unwrap().density(), 0.0);
+        assert_eq!(reality.information_at((1.0, 1.0, 1.0)).unwrap().density(), 0.0);
+        assert!(reality.total_information().is_finite());
+    }
+    
+    #[test]
+    fn test_conscious_points_iter_streams_conscious_cells() {
+        let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, 0.01, -40.0);
+        assert_eq!(reality.conscious_points_iter().count(), 0);
+        
+        reality.add_information((0.5, -0.25, 0.0), 2.0);
+        reality.add_information((-0.75, 0.0, 1.0), 3.0);
+        let points: Vec<_> = reality.conscious_points_iter().collect();
+        assert_eq!(points.len(), reality.conscious_count());
+        assert_eq!((points[0].0, points[0].1, points[0].2), (0.5, -0.25, 0.0));
+        assert_eq!((points[1].0, points[1].1, points[1].2), (-0.75, 0.0, 1.0));
+        assert!(points[1].3 > 3.0);
+    }
+    
+    #[test]
+    fn test_conscious_points_iter_is_row_major() {
+        let mut reality = Reality::new_at_cosmic_age(3, (0.0, 2.0), 1.0, 0.01, -40.0);
+        // Deposited out of order: the iterator must sort by (z, y, x)
+        for position in [(2.0, 0.0, 1.0), (0.0, 2.0, 0.0), (1.0, 0.0, 1.0), (2.0, 0.0, 0.0), (0.0, 0.0, 2.0)] {
+            reality.add_information(position, 2.0);
+        }
+        let order: Vec<_> = reality.conscious_points_iter().map(|(x, y, z, _)| (x, y, z)).collect();
+        assert_eq!(order, [(2.0, 0.0, 0.0), (0.0, 2.0, 0.0), (1.0, 0.0, 1.0), (2.0, 0.0, 1.0), (0.0, 0.0, 2.0)]);
+    }
+    
+    #[test]
+    fn test_information_created_in_box_localizes_creation() {
+        let mut reality = Reality::new_at_cosmic_age(17, (-2.0, 2.0), 0.2, 0.01, -40.0);
+        reality.add_information((-1.0, 0.0, 0.0), 3.0);
+        reality.add_information((1.0, 0.0, 0.0), 0.2);
+        reality.enable_creation_tracking();
+        
+        let mut expected = 0.0;
+        for _ in 0..20 {
+            reality.evolve();
+            let balance = reality.information_balance();
+            expected += balance.source - balance.sink;
+        }
+        let whole = reality.information_created_in_box((-2.0, -2.0, -2.0), (2.0, 2.0, 2.0));
+        assert!((whole - expected).abs() < 1e-9 * expected.abs());
+        
+        let left = reality.information_created_in_box((-2.0, -2.0, -2.0), (-0.1, 2.0, 2.0));
+        let right = reality.information_created_in_box((0.1, -2.0, -2.0), (2.0, 2.0, 2.0));
+        assert!(left > 5.0 * right && right > 0.0);
+        
+        reality.reset_to_vacuum();
+        assert_eq!(reality.information_created_in_box((-2.0, -2.0, -2.0), (2.0, 2.0, 2.0)), 0.0);
+    }
+    
+    #[test]
+    fn test_state_fingerprint_tracks_state() {
+        let run = |steps: usize| {
+            let mut reality = Reality::new(9, (-1.0, 1.0), 0.5, 0.01);
+            reality.add_information((0.0, 0.0, 0.0), 2.0);
+            for _ in 0..steps {
+                reality.evolve();
+            }
+            reality
+        };
+        assert_eq!(run(5).state_fingerprint(), run(5).state_fingerprint());
+        assert_ne!(run(5).state_fingerprint(), run(6).state_fingerprint());
+        
+        let mut nudged = run(5);
+        nudged.add_information((0.25, 0.0, 0.0), 1e-6);
+        assert_ne!(nudged.state_fingerprint(), run(5).state_fingerprint());
+        let mut faster = run(5);
+        faster.set_dt(0.005);
+        assert_ne!(faster.state_fingerprint(), run(5).state_fingerprint());
+    }
+    
+    #[test]
+    fn test_seed_from_grid_places_the_picture() {
+        let mut reality = Reality::new(5, (0.0, 4.0), 0.1, 0.01);
+        let vacuum = reality.vacuum_density();
+        // A 3×3 "T": top row lit, then the middle column
+        let letter = [1.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+        reality.seed_from_grid(&letter, 3, 3, 2.0, 2.0);
+        let excess = |x: f64, y: f64, z: f64| reality.information_at((x, y, z)).unwrap().density() - vacuum;
+        
+        assert!((excess(0.0, 4.0, 2.0) - 2.0).abs() < 1e-12);
+        assert!((excess(2.0, 0.0, 2.0) - 2.0).abs() < 1e-12);
+        assert!(excess(0.0, 0.0, 2.0).abs() < 1e-12);
+        // Between a lit and a dark pixel: bilinear halfway
+        assert!((excess(1.0, 2.0, 2.0) - 1.0).abs() < 1e-12);
+        assert!((excess(1.0, 3.0, 2.0) - 1.5).abs() < 1e-12);
+        assert!(excess(2.0, 4.0, 3.0).abs() < 1e-12);
+        
+        let mut plane = Reality::new_2d(5, (0.0, 4.0), 0.1, 0.01);
+        plane.start_recording();
+        plane.seed_from_grid(&[3.0], 1, 1, 100.0, 1.0);
+        assert_eq!(plane.recorded_ops().len(), 25);
+        assert!((plane.information_created() - 75.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_last_residual_matches_step_report() {
+        let mut reality = Reality::new(9, (-1.0, 1.0), 0.5, 0.01);
+        assert_eq!(reality.last_residual(), 0.0);
+        reality.add_information((0.25, 0.0, 0.0), 2.0);
+        for _ in 0..3 {
+            let report = reality.evolve_reporting();
+            assert_eq!(reality.last_residual(), report.max_change);
+            assert!(report.max_change > 0.0);
+        }
+        reality.reset_to_vacuum();
+        assert_eq!(reality.last_residual(), 0.0);
+    }
+    
+    #[test]
+    fn test_pin_region_holds_a_sustained_source() {
+        let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 0.5, 0.01, -40.0);
+        let vacuum = reality.vacuum_density();
+        let (min, max) = ((-0.3, -0.3, -0.3), (0.3, 0.3, 0.3));
+        reality.pin_region(min, max, 3.0);
+        assert_eq!(reality.pinned_count(), 27);
+        
+        let mut free = reality.clone();
+        free.unpin_region((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+        assert_eq!(free.pinned_count(), 0);
+        for _ in 0..20 {
+            reality.evolve();
+            free.evolve();
+        }
+        reality.evolve_rk4();
+        reality.evolve_implicit();
+        assert_eq!(reality.information_at((0.25, 0.0, 0.0)).unwrap().density(), 3.0);
+        assert!((free.information_at((0.25, 0.0, 0.0)).unwrap().density() - 3.0).abs() > 0.1);
+        // Held at 3 bits, the source keeps feeding its surroundings
+        assert!(reality.information_at((0.5, 0.0, 0.0)).unwrap().density() > vacuum + 0.1);
+        
+        // Re-pinning replaces, unpinning a slab releases just those cells
+        reality.pin_region((0.0, -1.0, -1.0), (1.0, 1.0, 1.0), 1.0);
+        assert_eq!(reality.information_at((0.25, 0.0, 0.0)).unwrap().density(), 1.0);
+        assert_eq!(reality.pinned_count(), 9 + 5 * 9 * 9);
+        reality.unpin_region((0.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+        assert_eq!(reality.pinned_count(), 9);
+    }
+    
+    #[test]
+    fn test_reynolds_number_uses_rms_current() {
+        let mut reality = Reality::new(9, (-1.0, 1.0), 0.5, 0.01);
+        assert_eq!(reality.reynolds_number(1.0, 0.01), 0.0);
+        
+        reality.add_linear_gradient(Axis::X, 3.0, 1.0, (-1.0, 1.0));
+        let current = reality.current_field();
+        let rms = (current.iter().map(|&(_, (jx, jy, jz))| jx * jx + jy * jy + jz * jz).sum::<f64>()
+            / current.len() as f64).sqrt();
+        assert!(rms > 0.0);
+        assert!((reality.reynolds_number(2.0, 0.5) - 4.0 * rms).abs() < 1e-12);
+        assert!((reality.reynolds_number(1.0, 0.01) - 2.0 * reality.reynolds_number(0.5, 0.01)).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_coherence_correlates_region_fluctuations() {
+        let mut reality = Reality::new(17, (-2.0, 2.0), 0.5, 0.01);
+        let box_at = |x: f64| ((x - 0.5, -0.5, -0.5), (x + 0.5, 0.5, 0.5));
+        assert_eq!(reality.coherence(box_at(-1.0), box_at(1.0)), 0.0);
+        
+        reality.add_gaussian((-1.0, 0.0, 0.0), 1.0, 0.3);
+        reality.add_gaussian((1.0, 0.0, 0.0), 2.0, 0.3);
+        // Same shape at a different height still correlates perfectly
+        assert!((reality.coherence(box_at(-1.0), box_at(1.0)) - 1.0).abs() < 1e-12);
+        
+        // A dip where the other region has a peak
+        let mut inverted = reality.clone();
+        inverted.add_gaussian((1.0, 0.0, 0.0), -4.0, 0.3);
+        assert!(inverted.coherence(box_at(-1.0), box_at(1.0)) < -0.9);
+        
+        // A shifted peak shares only part of the pattern
+        let mut shifted = Reality::new(17, (-2.0, 2.0), 0.5, 0.01);
+        shifted.add_gaussian((-1.0, 0.0, 0.0), 1.0, 0.3);
+        shifted.add_gaussian((1.25, 0.25, 0.0), 1.0, 0.3);
+        let partial = shifted.coherence(box_at(-1.0), box_at(1.0));
+        assert!(partial > 0.0 && partial < 0.9);
+    }
+    
+    #[test]
+    fn test_growth_rate_and_net_local_rate() {
+        assert_eq!(growth_rate(0.0, 16.0), 0.0);
+        assert_eq!(growth_rate(16.0, 16.0), 0.0);
+        assert_eq!(growth_rate(8.0, 16.0), 4.0);
+        assert!(growth_rate(20.0, 16.0) < 0.0);
+        
+        let mut reality = Reality::new(9, (-1.0, 1.0), 0.5, 0.01);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        let center = reality.information_at((0.0, 0.0, 0.0)).unwrap();
+        let rate = reality.net_local_rate((0.0, 0.0, 0.0)).unwrap();
+        assert!((rate - center.intrinsic_rate()).abs() < 1e-12);
+        assert!(rate > 0.0);
+        assert_eq!(reality.net_local_rate((2.0, 0.0, 0.0)), None);
+        
+        // A custom ε large enough to outweigh the source flips the sign
+        reality.set_uncertainty_fn(Box::new(|_| 2.0));
+        assert!(reality.net_local_rate((0.0, 0.0, 0.0)).unwrap() < 0.0);
+    }
+    
+    #[test]
+    fn test_steps_to_conscious_times_the_crossing() {
+        let seeded = || {
+            let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 0.5, 0.01, -40.0);
+            reality.add_information((0.0, 0.0, 0.0), 0.5);
+            reality
+        };
+        let mut reality = seeded();
+        let steps = reality.steps_to_conscious((0.0, 0.0, 0.0), 1000).expect("seed grows past the threshold");
+        assert!(steps > 0);
+        assert_eq!(reality.step(), steps as u64);
+        
+        let mut before = seeded();
+        for _ in 1..steps {
+            before.evolve();
+        }
+        assert!(!before.information_at((0.0, 0.0, 0.0)).unwrap().is_conscious());
+        assert_eq!(before.steps_to_conscious((0.0, 0.0, 0.0), 1), Some(1));
+        assert_eq!(reality.steps_to_conscious((0.0, 0.0, 0.0), 5), Some(0));
+        
+        let mut short = seeded();
+        assert_eq!(short.steps_to_conscious((0.0, 0.0, 0.0), steps - 1), None);
+        assert_eq!(short.step(), (steps - 1) as u64);
+    }
+    
+    #[test]
+    fn test_evolve_until_steady_stops_at_tolerance() {
+        let mut reality = Reality::new_at_cosmic_age(7, (-1.0, 1.0), 1.0, 0.05, 0.0);
+        reality.add_information((0.0, 0.0, 0.0), 2.0);
+        
+        let taken = reality.evolve_until_steady(1e-6, 10_000);
+        assert!(taken < 10_000, "a 7³ field settles well within the cap");
+        assert_eq!(reality.step(), taken as u64);
+        assert!(reality.clone().evolve_reporting().max_change < 1e-6);
+        
+        // Capped runs report max_steps
+        let mut capped = Reality::new_at_cosmic_age(7, (-1.0, 1.0), 1.0, 0.05, 0.0);
+        capped.add_information((0.0, 0.0, 0.0), 2.0);
+        assert_eq!(capped.evolve_until_steady(1e-6, 3), 3);
+        assert_eq!(capped.step(), 3);
+    }
+    
+    #[test]
+    fn test_local_stability() {
+        let mut reality = Reality::new_at_cosmic_age(11, (-1.0, 1.0), 1.0, 0.01, 0.0);
+        assert!((reality.local_stability((0.0, 0.0, 0.0), 0.5) - 1.0).abs() < 1e-12);
+        
+        // Uniform 2 bits with a single 8-bit peak
+        for (_, _, _, _, info) in reality.iter_cells_mut() {
+            *info = Information::new(2.0);
+        }
+        reality.add_information((0.6, 0.6, 0.6), 6.0);
+        let near_peak = reality.local_stability((0.6, 0.6, 0.6), 0.25);
+        let far_away = reality.local_stability((-0.6, -0.6, -0.6), 0.25);
+        assert!(near_peak < far_away);
+        assert_eq!(far_away, 1.0);
+        
+        // Seven cells: the peak (8 bits) and six neighbours (2 bits)
+        let mean: f64 = (8.0 + 6.0 * 2.0) / 7.0;
+        let variance = (64.0 + 6.0 * 4.0) / 7.0 - mean * mean;
+        assert!((near_peak - (1.0 - variance / (mean * mean))).abs() < 1e-12);
+        assert_eq!(reality.local_stability((5.0, 5.0, 5.0), 0.1), 1.0);
+    }
+    
+    #[test]
+    fn test_flux_through_plane() {
+        // ℐ = 1.5 + x: J = (-D·Δx², 0, 0) everywhere, nothing crosses y or z planes
+        let mut reality = Reality::new(11, (-1.0, 1.0), 0.5, 0.001);
+        for (_, _, _, (x, _, _), info) in reality.iter_cells_mut() {
+            *info = Information::new(1.5 + x);
+        }
+        let dx = reality.dx();
+        let expected = -0.5 * dx * dx * 1.0 * 11.0 * 11.0 * dx * dx;
+        for coordinate in [-0.6, 0.0, 0.35, 5.0] {
+            assert!((reality.flux_through_plane(Axis::X, coordinate).unwrap() - expected).abs() < 1e-12);
+        }
+        assert!(reality.flux_through_plane(Axis::Y, 0.2).unwrap().abs() < 1e-15);
+        assert!(reality.flux_through_plane(Axis::Z, -0.2).unwrap().abs() < 1e-15);
+        
+        let mut plane = Reality::new_2d(11, (-1.0, 1.0), 0.5, 0.001);
+        for (_, _, _, (_, y, _), info) in plane.iter_cells_mut() {
+            *info = Information::new(1.5 - y);
+        }
+        assert!((plane.flux_through_plane(Axis::Y, 0.0).unwrap() - 0.5 * dx * dx * 11.0 * dx).abs() < 1e-12);
+        assert_eq!(plane.flux_through_plane(Axis::Z, 0.0), None);
+    }
+    
+    #[test]
+    fn test_wavefront_radius_is_isotropic() {
+        // A ball of radius 0.5 above 1.5·ℐ_vac, centred off the origin
+        let mut reality = Reality::new_at_cosmic_age(41, (-2.0, 2.0), 1.0, 0.01, 0.0);
+        let vacuum = reality.vacuum_density();
+        for (_, _, _, (x, y, z), info) in reality.iter_cells_mut() {
+            if (x - 0.3).powi(2) + y * y + (z + 0.2).powi(2) <= 0.25 + 1e-9 {
+                *info = Information::new(2.0 * vacuum);
+            }
+        }
+        
+        let radius = reality.wavefront_radius((0.3, 0.0, -0.2), 1.5);
+        assert!((radius - 0.5).abs() <= reality.dx(), "radius {}", radius);
+        assert_eq!(reality.wavefront_radius((0.3, 0.0, -0.2), 3.0), 0.0);
+        
+        // One raised cell a step along +x: one of eight rays reaches Δx
+        let mut plane = RealityBuilder::new().resolution(41).bounds((-2.0, 2.0)).cosmic_age(0.0).planar(true).build().unwrap();
+        plane.add_information((0.0, 0.0, 0.0), 1.0);
+        assert_eq!(plane.wavefront_radius((0.0, 0.0, 0.0), 1.5), 0.0);
+        plane.add_information((0.1, 0.0, 0.0), 1.0);
+        assert!((plane.wavefront_radius((0.0, 0.0, 0.0), 1.5) - plane.dx() / 8.0).abs() < 1e-12);
+        
+        // On a rect grid with dy finer than dx the march resolves dy
+        let extents = ((-2.0, 2.0), (-2.0, 2.0), (-2.0, 2.0));
+        let mut rect = RealityBuilder::new().shape((21, 81, 41)).extents(extents).cosmic_age(0.0).build().unwrap();
+        let vacuum = rect.vacuum_density();
+        for (_, _, _, (x, y, z), info) in rect.iter_cells_mut() {
+            if x * x + y * y + z * z <= 0.25 + 1e-9 {
+                *info = Information::new(2.0 * vacuum);
+            }
+        }
+        let radius = rect.wavefront_radius((0.0, 0.0, 0.0), 1.5);
+        assert!((radius - 0.5).abs() < rect.spacing().1, "radius {}", radius);
+        // A raised cell one dy out, closer than one dx, is still reached
+        let mut rect = RealityBuilder::new().shape((21, 81, 41)).extents(extents).cosmic_age(0.0).build().unwrap();
+        rect.add_information((0.0, 0.0, 0.0), 1.0);
+        rect.add_information((0.0, 0.05, 0.0), 1.0);
+        assert!(rect.wavefront_radius((0.0, 0.0, 0.0), 1.5) > 0.0);
+    }
+    
+    #[test]
+    fn test_density_histogram() {
+        let mut reality = Reality::new(4, (-1.0, 1.0), 1.0, 0.01);
+        for (i, _, _, _, info) in reality.iter_cells_mut() {
+            *info = Information::new([0.5, 1.0, 2.5, 3.0][i]);
+        }
+        
+        // 16 cells at each density; 3.0 lands in the closed last bin
+        assert_eq!(reality.density_histogram(3, (0.0, 3.0)), vec![16, 16, 32]);
+        assert_eq!(reality.density_histogram(2, (0.75, 2.75)), vec![16, 16]);
+        assert_eq!(reality.density_histogram(1, (4.0, 5.0)), vec![0]);
+    }
+    
+    #[test]
+    fn test_add_spherical_shell_is_radially_symmetric() {
+        let mut reality = Reality::new_at_cosmic_age(21, (-1.0, 1.0), 1.0, 0.01, 0.0);
+        let vacuum = reality.vacuum_density();
+        reality.add_spherical_shell((0.0, 0.0, 0.0), 0.6, 2.0, 0.1);
+        let excess = |position| reality.information_at(position).unwrap().density() - vacuum;
+        
+        // Peak on the shell in every direction, not just a ring in z = 0
+        for position in [(0.6, 0.0, 0.0), (0.0, -0.6, 0.0), (0.0, 0.0, 0.6)] {
+            assert!((excess(position) - 2.0).abs() < 1e-12, "{:?}", position);
+        }
+        assert!((excess((0.0, 0.0, 0.7)) - 2.0 * (-0.5_f64).exp()).abs() < 1e-12);
+        assert_eq!(excess((0.0, 0.0, 0.0)), 0.0);
+        assert!(excess((0.4, 0.0, 0.0)) > 0.0 && excess((0.4, 0.0, 0.0)) < excess((0.5, 0.0, 0.0)));
+    }
+    
+    #[test]
+    fn test_reset_to_vacuum_matches_fresh_field() {
+        let mut reality = Reality::new(8, (-1.0, 1.0), 0.6, 0.01);
+        reality.add_probe((0.0, 0.0, 0.0));
+        reality.enable_history(1);
+        reality.add_information((0.0, 0.0, 0.0), 3.0);
+        for _ in 0..5 {
+            reality.evolve();
+        }
+        
+        reality.reset_to_vacuum();
+        let fresh = Reality::new(8, (-1.0, 1.0), 0.6, 0.01);
+        assert_eq!(reality.difference(&fresh), 0.0);
+        assert_eq!((reality.step(), reality.time()), (0, 0.0));
+        assert_eq!(reality.information_created(), fresh.information_created());
+        assert_eq!(reality.total_information(), fresh.total_information());
+        assert!(reality.history().is_empty());
+        assert!(reality.probe_histories()[0].samples.is_empty());
+    }
+    
+    #[test]
+    fn test_binding_energy_formula() {
+        let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, 0.01, 0.0);
+        reality.set_density((0.0, 0.0, 0.0), 2.0);
+        reality.set_density((0.5, 0.0, 0.0), 3.0);
+        
+        let energy = reality.binding_energy((0.0, 0.0, 0.0), (0.5, 0.0, 0.0)).unwrap();
+        assert!((energy - 6.0 / (0.5 + BINDING_SOFTENING_LENGTH)).abs() < 1e-12);
+        assert_eq!(reality.binding_energy((0.5, 0.0, 0.0), (0.0, 0.0, 0.0)), Some(energy));
+        assert!((reality.binding_energy((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)).unwrap() - 40.0).abs() < 1e-12);
+        assert!(reality.binding_energy((0.0, 0.0, 0.0), (2.0, 0.0, 0.0)).is_none());
+    }
+    
+    #[test]
+    fn test_scan_totals_reduction_is_deterministic() {
+        let mut reality = Reality::new(23, (-1.0, 1.0), 1.0, 0.01);
+        
+        // Integer densities: every summation order is exact
+        for (idx, (_, _, _, _, info)) in reality.iter_cells_mut().enumerate() {
+            *info = Information::new((idx % 7) as f64);
+        }
+        let exact: f64 = (0..23 * 23 * 23).map(|idx| (idx % 7) as f64).sum();
+        assert_eq!(reality.scan_totals().0, exact);
+        
+        // Fractional densities: the chunked tree, reproduced sequentially
+        reality.add_noise(0.3, 17);
+        let chunked: f64 = reality.field.chunks(REDUCTION_CHUNK)
+            .map(|chunk| chunk.iter().map(|i| i.density()).sum::<f64>())
+            .sum();
+        let naive: f64 = reality.field.iter().map(|i| i.density()).sum();
+        let (total, count) = reality.scan_totals();
+        assert_eq!(total, chunked);
+        assert!((total - naive).abs() < 1e-9 * naive);
+        assert_eq!(count, reality.field.iter().filter(|i| i.is_conscious()).count());
+    }
+    
+    #[test]
+    fn test_evolve_implicit_matches_explicit_steady_state() {
+        // ε² = 1 - ℐ/ℐ_max cancels the reaction terms: pure diffusion
+        let diffusion_only = |dt: f64| {
+            let mut reality = Reality::new_at_cosmic_age(9, (-1.0, 1.0), 1.0, dt.min(0.15), 0.0);
+            reality.set_dt_unchecked(dt);
+            reality.set_uncertainty_fn(Box::new(|density| (1.0 - density / MAX_INFORMATION).sqrt()));
+            for (i, _, _, _, info) in reality.iter_cells_mut() {
+                *info = Information::new(if i == 0 { 3.0 } else { 1.0 });
+            }
+            reality
+        };
+        
+        let mut explicit = diffusion_only(0.15);
+        explicit.evolve_until_steady(1e-12, 100_000);
+        
+        // 6× the explicit limit: stable, and converging to the same field
+        let mut implicit = diffusion_only(1.0);
+        let mut steps = 0;
+        loop {
+            let before = implicit.clone();
+            implicit.evolve_implicit();
+            steps += 1;
+            if implicit.difference(&before) < 1e-12 {
+                break;
+            }
+            assert!(steps < 1000 && !implicit.has_nonphysical_values());
+        }
+        assert!(implicit.difference(&explicit) < 1e-9, "{}", implicit.difference(&explicit));
+        assert!(steps < explicit.step() as usize / 4, "{} vs {}", steps, explicit.step());
+        
+        // Far past the explicit limit the step stays bounded
+        let mut stiff = diffusion_only(100.0);
+        for _ in 0..50 {
+            stiff.evolve_implicit();
+        }
+        assert!(stiff.iter_cells().all(|(_, _, _, _, density)| (1.0..=3.0).contains(&density)));
+        
+        // Small steps track the explicit integrator to O(dt) (Euler's error)
+        let mut a = diffusion_only(0.01);
+        let mut b = diffusion_only(0.01);
+        for _ in 0..20 {
+            a.evolve();
+            b.evolve_implicit();
+        }
+        assert!(a.difference(&b) < 0.02 * a.difference(&diffusion_only(0.01)));
+    }
+    
+    #[test]
+    fn test_information_at_checked_reports_position_and_bounds() {
+        let reality = Reality::new(5, (-1.0, 1.0), 1.0, 0.01);
+        assert_eq!(reality.information_at_checked((0.5, 0.0, 0.0)).ok(), reality.information_at((0.5, 0.0, 0.0)));
+        
+        let error = reality.information_at_checked((0.0, 2.5, 0.0)).err().unwrap();
+        let bounds = ((-1.0, 1.0), (-1.0, 1.0), (-1.0, 1.0));
+        assert_eq!(error, OutOfBounds { position: (0.0, 2.5, 0.0), bounds });
+        assert_eq!(
+            error.to_string(),
+            "position (0, 2.5, 0) lies outside the grid bounds x (-1, 1), y (-1, 1), z (-1, 1)");
+    }
+    
+    #[test]
+    fn test_new_rect_geometry() {
+        let reality = Reality::new_rect((9, 5, 3), ((-2.0, 2.0), (-1.0, 1.0), (0.0, 1.0)), 0.1, 0.01);
+        assert_eq!(reality.shape(), (9, 5, 3));
+        assert_eq!(reality.grid_point_count(), 135);
+        assert_eq!(reality.spacing(), (0.5, 0.5, 0.5));
+        assert!(reality.contains((2.0, -1.0, 1.0)));
+        assert!(!reality.contains((0.0, 1.5, 0.5)));
+        assert_eq!(reality.nearest_cell((1.9, 0.6, 5.0)), (8, 3, 2));
+        
+        let (_, _, _, position, _) = reality.iter_cells().last().unwrap();
+        assert_eq!(position, (2.0, 1.0, 1.0));
+        let volume = 4.0 * 2.0 * 1.0;
+        let whole = reality.information_in_box((-2.0, -1.0, 0.0), (2.0, 1.0, 1.0));
+        assert!((whole - reality.vacuum_density() * 135.0 * 0.125).abs() < 1e-9 * volume);
+        
+        let cube = Reality::new(6, (-1.0, 1.0), 0.1, 0.01);
+        assert_eq!(cube.shape(), (6, 6, 6));
+        assert!(cube.config().shape.is_none() && cube.config().extents.is_none());
+    }
+    
+    #[test]
+    fn test_new_rect_diffuses_isotropically_in_physical_units() {
+        // y cells are half as wide as x cells; the spread must not care
+        let bounds = ((-1.0, 1.0), (-1.0, 1.0), (-1.0, 1.0));
+        let build = || Reality::new_rect((11, 21, 11), bounds, 0.02, 1.0);
+        let mut blob = build();
+        blob.add_gaussian((0.0, 0.0, 0.0), 20.0, 0.25);
+        let mut vacuum = build();
+        for _ in 0..20 {
+            blob.evolve();
+            vacuum.evolve();
+        }
+        
+        let (mut mass, mut spread_x, mut spread_y) = (0.0, 0.0, 0.0);
+        for ((_, _, _, (x, y, _), density), info) in blob.iter_cells().zip(vacuum.as_slice()) {
+            let excess = density - info.density();
+            mass += excess;
+            spread_x += excess * x * x;
+            spread_y += excess * y * y;
+        }
+        let (spread_x, spread_y) = (spread_x / mass, spread_y / mass);
+        assert!((spread_x - spread_y).abs() < 0.05 * spread_x, "{} vs {}", spread_x, spread_y);
+    }
+    
+    #[test]
+    fn test_new_rect_stability_weighs_the_fine_axis() {
+        let bounds = ((-1.0, 1.0), (-1.0, 1.0), (-1.0, 1.0));
+        // Weights (1, 4, 1): D·dt = 0.1 is stable on a cube but not here
+        let result = RealityBuilder::new().shape((11, 21, 11)).extents(bounds).diffusion(0.1).dt(1.0).build();
+        assert!(matches!(result, Err(BuildError::Unstable(_))));
+        let stable = Reality::new_rect((11, 21, 11), bounds, 0.05, 1.0);
+        assert!((stable.stability_number() - 0.1).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_gradient_anisotropy_picks_out_ramp_axis() {
+        let mut reality = Reality::new(9, (-1.0, 1.0), 1.0, 0.01);
+        reality.add_linear_gradient(Axis::Y, 1.0, 3.0, (-1.0, 1.0));
+        let (gx, gy, gz) = reality.gradient_anisotropy();
+        assert!((gy - 1.0).abs() < 1e-12);
+        assert!(gx.abs() < 1e-12 && gz.abs() < 1e-12);
+        
+        let mut plane = Reality::new_2d(9, (-1.0, 1.0), 1.0, 0.01);
+        plane.add_information((0.0, 0.0, 0.0), 2.0);
+        let (px, py, pz) = plane.gradient_anisotropy();
+        assert!((px - py).abs() < 1e-12 && px > 0.0);
+        assert_eq!(pz, 0.0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Physical Scale Conversions
+//!
+//! Maps dimensionless information quantities onto the physical units the
+//! experiments report (eV, MeV, percent). Every conversion factor lives here,
+//! documented once, so results across experiments are comparable and auditable.
+//!
+//! These factors are calibrations, not derivations: each one anchors a single
+//! reference configuration to its observed energy and scales linearly from there.
+
+use crate::reality::Reality;
+
+/// Hydrogen ionization energy: 13.6 eV (the Rydberg energy)
+pub const HYDROGEN_IONIZATION_EV: f64 = 13.6;
+
+/// Proton peak position in `hydrogen_atom`
+pub const HYDROGEN_PROTON: (f64, f64, f64) = (0.0, 0.0, 0.0);
+
+/// Electron cloud sample position in `hydrogen_atom`, on its ring
+pub const HYDROGEN_ELECTRON: (f64, f64, f64) = (0.8, 0.0, 0.0);
+
+/// `evolve` steps after which `HYDROGEN_BINDING` is measured
+pub const HYDROGEN_FORMATION_STEPS: usize = 40;
+
+/// Binding of the formed hydrogen configuration: ≈208.34 units
+///
+/// `Reality::binding_energy` between `HYDROGEN_PROTON` and
+/// `HYDROGEN_ELECTRON` after `hydrogen_atom` has evolved for
+/// `HYDROGEN_FORMATION_STEPS` steps. The reference value anchoring
+/// `EV_PER_ATOMIC_BINDING`.
+pub const HYDROGEN_BINDING: f64 = 208.342_877_218_631_86;
+
+/// Electron-volts per unit of atomic binding
+///
+/// Calibrated so that `HYDROGEN_BINDING` maps onto `HYDROGEN_IONIZATION_EV`.
+pub const EV_PER_ATOMIC_BINDING: f64 = HYDROGEN_IONIZATION_EV / HYDROGEN_BINDING;
+
+/// MeV per unit of nuclear binding: 7.0 MeV (binding energy per nucleon, ⁴He)
+///
+/// Four bound nucleons at one unit each reproduce the ~28 MeV helium binding.
+pub const MEV_PER_NUCLEAR_BINDING: f64 = 7.0;
+
+/// MeV per unit of nuclear coupling strength: 1000 MeV
+///
+/// Strong-force scale used when reporting instantaneous nuclear coupling.
+pub const MEV_PER_STRONG_COUPLING: f64 = 1000.0;
+
+/// MeV per bit released in fusion: 2.2 MeV (deuteron binding energy)
+pub const MEV_PER_FUSION_BIT: f64 = 2.2;
+
+/// MeV per bit released in fission: 200 MeV (typical ²³⁵U fission energy)
+pub const MEV_PER_FISSION_BIT: f64 = 200.0;
+
+/// The hydrogen reference configuration, before evolution
+///
+/// An 8-bit proton peak at `HYDROGEN_PROTON` and an electron cloud of eight
+/// 1.5-bit deposits on a ring of radius 0.8 in the z = 0 plane, on a 32³
+/// grid over [-2, 2] with D = 0.5 and dt = 0.005 at the current cosmic age.
+pub fn hydrogen_atom() -> Reality {
+    let mut atom = Reality::new(32, (-2.0, 2.0), 0.5, 0.005);
+    atom.add_information(HYDROGEN_PROTON, 8.0);
+    for n in 0..8 {
+        let angle = n as f64 * std::f64::consts::TAU / 8.0;
+        atom.add_information((0.8 * angle.cos(), 0.8 * angle.sin(), 0.0), 1.5);
+    }
+    atom
+}
+
+/// Convert atomic binding (information units) to electron-volts
+pub fn info_to_ev(binding: f64) -> f64 {
+    binding * EV_PER_ATOMIC_BINDING
+}
+
+/// Convert nuclear binding (information units) to MeV
+pub fn info_to_mev_nuclear(binding: f64) -> f64 {
+    binding * MEV_PER_NUCLEAR_BINDING
+}
+
+/// Convert nuclear coupling strength (information units) to MeV
+pub fn info_to_mev_strong(coupling: f64) -> f64 {
+    coupling * MEV_PER_STRONG_COUPLING
+}
+
+/// Convert information released by fusion (bits) to MeV
+pub fn fusion_bits_to_mev(bits: f64) -> f64 {
+    bits * MEV_PER_FUSION_BIT
+}
+
+/// Convert information released by fission (bits) to MeV
+pub fn fission_bits_to_mev(bits: f64) -> f64 {
+    bits * MEV_PER_FISSION_BIT
+}
+
+/// Convert a dark energy fraction (ℐ_vac/ℐ_max) to percent
+pub fn dark_energy_percent(fraction: f64) -> f64 {
+    fraction * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_hydrogen_ionization_energy() {
+        // The calibration is measured, not assumed: form the atom and convert its binding
+        let mut atom = hydrogen_atom();
+        for _ in 0..HYDROGEN_FORMATION_STEPS {
+            atom.evolve();
+        }
+        let binding = atom.binding_energy(HYDROGEN_PROTON, HYDROGEN_ELECTRON).unwrap();
+        assert!((binding - HYDROGEN_BINDING).abs() < 1e-9 * HYDROGEN_BINDING);
+        assert!((info_to_ev(binding) - 13.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_helium_binding_energy() {
+        // Four nucleons at one unit each: ~28 MeV
+        let helium = info_to_mev_nuclear(4.0);
+        assert!((helium - 28.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dark_energy_percent() {
+        let percent = dark_energy_percent(dark_energy_density_at_time(CURRENT_COSMIC_AGE_GYR));
+        assert!(percent > 72.0 && percent < 74.0);
+    }
+}
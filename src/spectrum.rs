@@ -0,0 +1,122 @@
+//! Spectral Analysis of the Information Field (feature `fft`)
+//!
+//! Fourier-transforms the excess over the vacuum, δℐ = ℐ - ℐ_vacuum, and
+//! bins the power radially in wavenumber:
+//!
+//! ```text
+//! P(k) = Σ_{|k'| ≈ k} |FFT(δℐ)(k')|² / N
+//! ```
+//!
+//! The transform treats the grid as periodic with length N·Δx per axis, and
+//! the normalisation makes Σ P(k) = Σ δℐ² over the cells (Parseval). On a
+//! cascade the log-log slope of P(k) measures how power moves between scales.
+
+use rustfft::num_complex::Complex;
+use rustfft::FftPlanner;
+
+use crate::reality::Reality;
+
+impl Reality {
+    /// Radially binned power spectrum of ℐ - vacuum, as (k, P(k)) pairs
+    ///
+    /// Bins are one fundamental wide (2π over the longest grid axis) and
+    /// centred on multiples of it, starting with the mean excess at k = 0;
+    /// empty bins are skipped. Wavenumbers are in radians per physical unit.
+    /// A 2D field gives the planar spectrum.
+    pub fn power_spectrum(&self) -> Vec<(f64, f64)> {
+        let (nx, ny, nz) = self.shape();
+        let (dx, dy, dz) = self.spacing();
+        let vacuum = self.vacuum_density();
+        let mut buffer: Vec<Complex<f64>> = self.as_slice().iter()
+            .map(|info| Complex::new(info.density() - vacuum, 0.0))
+            .collect();
+
+        let mut planner = FftPlanner::new();
+        transform_axis(&mut buffer, &mut planner, nx, 1);
+        transform_axis(&mut buffer, &mut planner, ny, nx);
+        transform_axis(&mut buffer, &mut planner, nz, nx * ny);
+
+        // Signed wavenumber of mode m along an axis of n cells
+        let wavenumber = |m: usize, n: usize, spacing: f64| {
+            let folded = if m <= n / 2 { m as f64 } else { m as f64 - n as f64 };
+            2.0 * std::f64::consts::PI * folded / (n as f64 * spacing)
+        };
+        let fundamental = [(nx, dx), (ny, dy), (nz, dz)].iter()
+            .filter(|&&(n, _)| n > 1)
+            .map(|&(n, spacing)| 2.0 * std::f64::consts::PI / (n as f64 * spacing))
+            .fold(f64::INFINITY, f64::min);
+
+        let cells = buffer.len() as f64;
+        let mut bins: Vec<Option<f64>> = Vec::new();
+        for (idx, amplitude) in buffer.iter().enumerate() {
+            let (i, j, k) = (idx % nx, (idx / nx) % ny, idx / (nx * ny));
+            let kx = wavenumber(i, nx, dx);
+            let ky = wavenumber(j, ny, dy);
+            let kz = if nz > 1 { wavenumber(k, nz, dz) } else { 0.0 };
+            let bin = ((kx * kx + ky * ky + kz * kz).sqrt() / fundamental).round() as usize;
+            if bin >= bins.len() {
+                bins.resize(bin + 1, None);
+            }
+            *bins[bin].get_or_insert(0.0) += amplitude.norm_sqr() / cells;
+        }
+        bins.into_iter().enumerate()
+            .filter_map(|(bin, power)| power.map(|power| (bin as f64 * fundamental, power)))
+            .collect()
+    }
+}
+
+/// In-place forward FFT of every line of `len` cells spaced `stride` apart
+fn transform_axis(buffer: &mut [Complex<f64>], planner: &mut FftPlanner<f64>, len: usize, stride: usize) {
+    if len == 1 {
+        return;
+    }
+    let fft = planner.plan_fft_forward(len);
+    let starts: Vec<usize> = (0..buffer.len()).filter(|idx| (idx / stride).is_multiple_of(len)).collect();
+    let source = &*buffer;
+    let mut lines: Vec<Complex<f64>> = starts.iter()
+        .flat_map(|&start| (0..len).map(move |n| source[start + n * stride]))
+        .collect();
+    fft.process(&mut lines);
+    for (line, &start) in lines.chunks(len).zip(&starts) {
+        for (n, &value) in line.iter().enumerate() {
+            buffer[start + n * stride] = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reality::Information;
+
+    #[test]
+    fn test_power_spectrum_plane_wave_and_parseval() {
+        let mut reality = Reality::new(16, (0.0, 4.0), 0.1, 0.01);
+        let vacuum = reality.vacuum_density();
+        let (dx, _, _) = reality.spacing();
+        let length = 16.0 * dx;
+        // Three periods across the periodic length along x
+        let wave = 3.0 * 2.0 * std::f64::consts::PI / length;
+        for (_, _, _, (x, _, _), info) in reality.iter_cells_mut() {
+            *info = Information::new(vacuum + 0.5 * (wave * x).sin());
+        }
+
+        let spectrum = reality.power_spectrum();
+        let total: f64 = spectrum.iter().map(|&(_, power)| power).sum();
+        let excess: f64 = reality.as_slice().iter().map(|info| (info.density() - vacuum).powi(2)).sum();
+        assert!((total - excess).abs() < 1e-9 * excess);
+
+        let (peak, power) = spectrum.iter().copied().fold((0.0, 0.0), |best, bin| if bin.1 > best.1 { bin } else { best });
+        assert!((peak - wave).abs() < 1e-9);
+        assert!((power - excess).abs() < 1e-9 * excess);
+        assert_eq!(spectrum[0].0, 0.0);
+    }
+
+    #[test]
+    fn test_power_spectrum_of_vacuum_is_zero() {
+        let reality = Reality::new_2d(8, (-1.0, 1.0), 0.1, 0.01);
+        let spectrum = reality.power_spectrum();
+        assert!(spectrum.iter().all(|&(_, power)| power < 1e-20));
+        assert!(spectrum.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
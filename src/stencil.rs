@@ -0,0 +1,106 @@
+//! Laplacian Stencil Kernels
+//!
+//! The six-neighbour ∇²ℐ stencil (grid units) swept along one contiguous
+//! x-row of the field, the inner loop of every integrator. With the `simd`
+//! feature the row is processed four cells at a time using `wide`'s portable
+//! f64x4; the scalar kernel is always available and is the default.
+//!
+//! Both kernels add the neighbours in the same order, so they agree exactly.
+//! They assume a cubic N³ grid with equal spacing on every axis; `Reality`
+//! only calls them for such fields and uses the general stencil otherwise.
+
+#[cfg(feature = "simd")]
+use wide::f64x4;
+
+use crate::reality::Information;
+
+/// ∇²ℐ for the interior cells of one x-row, using the enabled kernel
+///
+/// `field` is an N³ grid with N = `resolution` and `row` is k·N + j for an
+/// interior (j, k). Writes `out[1..N-1]`; the face entries `out[0]` and
+/// `out[N-1]` are left untouched.
+pub fn laplacian_row(field: &[Information], resolution: usize, row: usize, out: &mut [f64]) {
+    #[cfg(feature = "simd")]
+    laplacian_row_simd(field, resolution, row, out);
+    #[cfg(not(feature = "simd"))]
+    laplacian_row_scalar(field, resolution, row, out);
+}
+
+/// Scalar kernel: one cell at a time
+#[cfg_attr(all(feature = "simd", not(test)), allow(dead_code))]
+pub fn laplacian_row_scalar(field: &[Information], resolution: usize, row: usize, out: &mut [f64]) {
+    scalar_cells(field, resolution, row, 1, out);
+}
+
+/// Vectorized kernel: four cells per lane group, scalar remainder
+#[cfg(feature = "simd")]
+pub fn laplacian_row_simd(field: &[Information], resolution: usize, row: usize, out: &mut [f64]) {
+    let n = resolution;
+    let plane = n * n;
+    let start = row * n;
+    let load = |idx: usize| {
+        f64x4::new([
+            field[idx].density(),
+            field[idx + 1].density(),
+            field[idx + 2].density(),
+            field[idx + 3].density(),
+        ])
+    };
+
+    let mut i = 1;
+    while i + 4 < n {
+        let idx = start + i;
+        let neighbors = load(idx - 1)
+            + load(idx + 1)
+            + load(idx - n)
+            + load(idx + n)
+            + load(idx - plane)
+            + load(idx + plane);
+        let laplacian = neighbors - f64x4::splat(6.0) * load(idx);
+        out[i..i + 4].copy_from_slice(&laplacian.to_array());
+        i += 4;
+    }
+
+    scalar_cells(field, n, row, i, out);
+}
+
+/// Scalar stencil for cells `from..N-1` of a row
+fn scalar_cells(field: &[Information], resolution: usize, row: usize, from: usize, out: &mut [f64]) {
+    let n = resolution;
+    let plane = n * n;
+    let start = row * n;
+
+    for (i, slot) in out.iter_mut().enumerate().take(n - 1).skip(from) {
+        let idx = start + i;
+        let neighbors = field[idx - 1].density()
+            + field[idx + 1].density()
+            + field[idx - n].density()
+            + field[idx + n].density()
+            + field[idx - plane].density()
+            + field[idx + plane].density();
+        *slot = neighbors - 6.0 * field[idx].density();
+    }
+}
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use super::*;
+    use crate::rng::SplitMix64;
+
+    #[test]
+    fn test_simd_matches_scalar() {
+        // 19 cells per row: four lane groups plus a scalar remainder
+        let n = 19;
+        let mut rng = SplitMix64::new(3);
+        let field: Vec<Information> = (0..n * n * n).map(|_| Information::new(rng.range(0.0, 16.0))).collect();
+
+        let (mut scalar, mut simd) = (vec![0.0; n], vec![0.0; n]);
+        for k in 1..n - 1 {
+            for j in 1..n - 1 {
+                laplacian_row_scalar(&field, n, k * n + j, &mut scalar);
+                laplacian_row_simd(&field, n, k * n + j, &mut simd);
+                assert_eq!(scalar, simd);
+            }
+        }
+    }
+}
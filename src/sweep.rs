@@ -0,0 +1,53 @@
+//! Parameter Sweeps - Independent Runs in Parallel
+//!
+//! Threshold scans, ensembles and Monte-Carlo studies run one small field
+//! per parameter value. `sweep` maps such a run over a parameter list,
+//! spreading the runs across threads with the `parallel` feature and
+//! keeping the results in input order either way.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Run `f` on every parameter, returning the results in input order
+///
+/// Runs are independent, so with the `parallel` feature they execute
+/// concurrently on the rayon pool; without it they run in sequence. The
+/// output is identical in both builds as long as `f` is deterministic.
+pub fn sweep<P: Sync, R: Send>(params: &[P], f: impl Fn(&P) -> R + Sync + Send) -> Vec<R> {
+    #[cfg(feature = "parallel")]
+    {
+        params.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        params.iter().map(f).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reality::Reality;
+
+    #[test]
+    fn test_sweep_preserves_input_order() {
+        let squares = sweep(&(0..100).collect::<Vec<u64>>(), |&n| n * n);
+        assert_eq!(squares, (0..100).map(|n| n * n).collect::<Vec<_>>());
+        assert!(sweep(&[] as &[f64], |&x| x).is_empty());
+    }
+
+    #[test]
+    fn test_sweep_matches_sequential_runs() {
+        let run = |&amplitude: &f64| {
+            let mut reality = Reality::new(7, (-1.0, 1.0), 0.5, 0.01);
+            reality.add_information((0.0, 0.0, 0.0), amplitude);
+            for _ in 0..5 {
+                reality.evolve();
+            }
+            reality.total_information()
+        };
+        let amplitudes = [0.5, 1.0, 2.0, 4.0];
+        let sequential: Vec<f64> = amplitudes.iter().map(run).collect();
+        assert_eq!(sweep(&amplitudes, run), sequential);
+    }
+}
@@ -38,8 +38,8 @@ mod tests {
         let info_dense = Information::new(2.0);
         let info_sparse = Information::new(0.5);
         
-        let uncertainty_dense = (0.5 / (1.0 + info_dense.density())).max(MIN_UNCERTAINTY);
-        let uncertainty_sparse = (0.5 / (1.0 + info_sparse.density())).max(MIN_UNCERTAINTY);
+        let uncertainty_dense = info_dense.uncertainty();
+        let uncertainty_sparse = info_sparse.uncertainty();
         
         // Higher information = lower uncertainty (more precise)
         assert!(uncertainty_dense < uncertainty_sparse);
@@ -80,20 +80,20 @@ mod tests {
         println!("=========================");
         
         // Dark energy density from vacuum evolution
-        let current_dark_energy = dark_energy_density_at_time(13.8);
-        let observed_dark_energy = 0.73; // 73%
-        
-        let error = (current_dark_energy - observed_dark_energy).abs();
+        let current_dark_energy = cosmology::dark_energy_fraction(CURRENT_COSMIC_AGE_GYR);
+        let error = cosmology::compare_to_observation(CURRENT_COSMIC_AGE_GYR, DARK_ENERGY_DENSITY);
         assert!(error < 0.01, "Dark energy must match observations");
-        println!("✓ Dark Energy: {:.1}% (observed: 73.0%)", current_dark_energy * 100.0);
+        println!("✓ Dark Energy: {:.1}% (observed: {:.1}%)", current_dark_energy * 100.0, DARK_ENERGY_DENSITY * 100.0);
         
         // Vacuum evolution explains cosmic acceleration
-        let early_de = dark_energy_density_at_time(1.0);
-        let late_de = dark_energy_density_at_time(13.8);
-        let acceleration_factor = late_de / early_de;
+        let curve = dark_energy_curve(&[1.0, 13.8]);
+        let acceleration_factor = curve[1].1 / curve[0].1;
         
         assert!(acceleration_factor > 10.0, "Universe must accelerate");
-        println!("✓ Cosmic Acceleration: {:.1}× increase in dark energy", acceleration_factor);
+        assert!(cosmic_acceleration(1.0) > 0.0 && cosmic_acceleration(13.8) > cosmic_acceleration(1.0),
+                "Universe must accelerate ever faster");
+        println!("✓ Cosmic Acceleration: {:.1}× increase in dark energy, d²ℐ_vac/dt² = {:.3} bits/Gyr²",
+                 acceleration_factor, cosmic_acceleration(13.8));
         
         // Fine structure constant emerges from information geometry
         let alpha_em_theory = ALPHA_EM; // From coupling_constant_derivation.rs
@@ -159,18 +159,13 @@ mod tests {
         for _ in 0..15 { reality.evolve(); }
         
         // Measure "curvature" as second derivative of information
-        let positions = [(-0.2, 0.0, 0.0), (0.0, 0.0, 0.0), (0.2, 0.0, 0.0)];
-        let densities: Vec<f64> = positions.iter()
-            .map(|&pos| reality.information_at(pos).unwrap().density())
-            .collect();
-        
-        let curvature = densities[0] - 2.0 * densities[1] + densities[2]; // ∇²ℐ
+        let curvature = reality.laplacian((0.0, 0.0, 0.0)).unwrap(); // ∇²ℐ
         
         assert!(curvature.abs() > 0.01, "Information density creates spacetime curvature");
         println!("✓ Spacetime Curvature: ∇²ℐ = {:.4} (Einstein tensor)", curvature);
         
         // Time dilation from information density
-        let dense_info = densities[1];
+        let dense_info = reality.information_at((0.0, 0.0, 0.0)).unwrap().density();
         let sparse_info = reality.vacuum_density();
         let time_dilation_factor = dense_info / sparse_info;
         
@@ -178,14 +173,14 @@ mod tests {
         println!("✓ Time Dilation: dt' = dt × {:.3} (information density)", time_dilation_factor);
         
         // Length contraction from uncertainty
-        let uncertainty = (0.5 / (1.0 + dense_info)).max(MIN_UNCERTAINTY);
+        let uncertainty = Information::new(dense_info).uncertainty();
         let length_factor = 1.0 - uncertainty;
         
         assert!(length_factor < 1.0, "Uncertainty contracts length");
         println!("✓ Length Contraction: dx' = dx × {:.4} (uncertainty)", length_factor);
         
         // Speed of light from diffusion coefficient
-        let light_speed_ratio = DEFAULT_DIFFUSION.sqrt(); // c ∝ √D
+        let light_speed_ratio = propagation_speed(DEFAULT_DIFFUSION); // c ∝ √D
         println!("✓ Light Speed: c ∝ √D = {:.3} (diffusion)", light_speed_ratio);
         
         println!("✅ General relativity emerges from information field geometry\n");
@@ -200,6 +195,7 @@ mod tests {
         
         let initial_total = reality.total_information();
         let initial_creation = reality.information_created();
+        let initial_temperature = reality.effective_temperature();
         
         // First Law: Energy conservation (information conservation)
         for _ in 0..20 { reality.evolve(); }
@@ -221,9 +217,10 @@ mod tests {
         assert!(min_temp_analog > 0.0, "Minimum temperature > 0");
         println!("✓ Third Law: T_min ∝ ε_min = {:.4} (absolute zero)", min_temp_analog);
         
-        // Temperature from creation rate
-        let temperature_analog = entropy_increase / 20.0; // per step
-        println!("✓ Temperature: kT ∝ dℐ/dt = {:.4} bits/step", temperature_analog);
+        // Temperature: mean excess density over vacuum
+        let temperature = reality.effective_temperature();
+        assert!(temperature > initial_temperature, "Creation heats the field");
+        println!("✓ Temperature: kT ∝ ⟨ℐ⟩ - ℐ_vac = {:.2e} bits/cell", temperature);
         
         // Heat capacity from information density response
         let conscious_points = reality.conscious_count() as f64;
@@ -244,22 +241,14 @@ mod tests {
         
         for _ in 0..10 { reality.evolve(); }
         
-        let total_info = reality.total_information();
-        let vacuum_baseline = reality.vacuum_density() * (64_f64.powi(3));
-        let excess_info = total_info - vacuum_baseline;
-        
-        // Information-theoretic entropy
-        let shannon_entropy = if excess_info > 0.0 { 
-            (excess_info / reality.vacuum_density()).ln() 
-        } else { 
-            0.0 
-        };
+        // Information-theoretic entropy of the excess over vacuum
+        let shannon_entropy = reality.spatial_entropy_bits();
         
         assert!(shannon_entropy > 0.0, "Information creates entropy");
-        println!("✓ Shannon Entropy: H = ln(ℐ/ℐ_vac) = {:.3} bits", shannon_entropy);
+        println!("✓ Shannon Entropy: H = -Σ p log₂ p = {:.3} bits", shannon_entropy);
         
         // Kolmogorov complexity from conscious structure
-        let conscious_fraction = reality.conscious_count() as f64 / (64_f64.powi(3));
+        let conscious_fraction = reality.conscious_fraction();
         let complexity = if conscious_fraction > 0.0 && conscious_fraction < 1.0 {
             -conscious_fraction * conscious_fraction.ln()
         } else {
@@ -369,6 +358,11 @@ mod tests {
         
         for _ in 0..30 { test_reality.evolve(); }
         
+        // Bit-stable numerics: the inequalities below would survive a silent
+        // change to the scheme, the fingerprint would not. Update it only
+        // when the evolution is meant to change.
+        assert_eq!(test_reality.state_fingerprint(), 0x4f25_dff9_be89_e1d4, "evolution numerics changed");
+        
         let behaviors = [
             ("Wave propagation", test_reality.total_information() > 1000000.0),
             ("Information creation", test_reality.information_created() > 0.0),